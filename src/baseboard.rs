@@ -0,0 +1,137 @@
+//! Baseboard or module information (type 2).
+
+use table::{Handles, RawStructure};
+use Type;
+
+/// Safe view of a baseboard information structure.
+#[derive(Debug, Copy, Clone)]
+pub struct BaseboardInformationView<'a> {
+    raw: RawStructure<'a>,
+}
+
+impl<'a> BaseboardInformationView<'a> {
+    /// Interprets a raw structure as baseboard information.
+    ///
+    /// Returns `None` if the structure has a different type or is too short.
+    pub fn new(raw: RawStructure<'a>) -> Option<Self> {
+        if raw.ty() != Type::BaseboardInformation || raw.formatted().len() < 0x08 {
+            return None;
+        }
+        Some(BaseboardInformationView { raw })
+    }
+
+    /// The underlying structure.
+    pub fn raw(&self) -> RawStructure<'a> {
+        self.raw
+    }
+
+    /// Board manufacturer.
+    pub fn manufacturer(&self) -> Option<&'a str> {
+        self.raw.string_lossy(0x04)
+    }
+
+    /// Board product name.
+    pub fn product(&self) -> Option<&'a str> {
+        self.raw.string_lossy(0x05)
+    }
+
+    /// Board version.
+    pub fn version(&self) -> Option<&'a str> {
+        self.raw.string_lossy(0x06)
+    }
+
+    /// Board serial number.
+    pub fn serial_number(&self) -> Option<&'a str> {
+        self.raw.string_lossy(0x07)
+    }
+
+    /// Board asset tag.
+    pub fn asset_tag(&self) -> Option<&'a str> {
+        self.raw.string_lossy(0x08)
+    }
+
+    /// Board feature flags.
+    pub fn features(&self) -> Option<BaseboardFeatures> {
+        self.raw
+            .byte(0x09)
+            .map(BaseboardFeatures::from_bits_truncate)
+    }
+
+    /// Location of the board within the chassis.
+    pub fn location_in_chassis(&self) -> Option<&'a str> {
+        self.raw.string_lossy(0x0A)
+    }
+
+    /// Handle of the chassis in which this board resides.
+    pub fn chassis_handle(&self) -> Option<u16> {
+        self.raw.word(0x0B)
+    }
+
+    /// Type of the board.
+    pub fn board_type(&self) -> Option<BoardType> {
+        self.raw.byte(0x0D).map(BoardType::from)
+    }
+
+    /// Handles of the structures contained by this board,
+    /// such as processors, memory devices or other boards.
+    pub fn contained_object_handles(&self) -> Handles<'a> {
+        let count = self.raw.byte(0x0E).unwrap_or(0);
+        self.raw.handles(0x0F, count as usize)
+    }
+}
+
+bitflags! {
+    /// Baseboard feature flags.
+    pub struct BaseboardFeatures: u8 {
+        /// The board is a hosting board, such as a motherboard.
+        const HOSTING_BOARD = 1 << 0;
+        /// The board requires at least one daughter board or auxiliary card to function.
+        const REQUIRES_DAUGHTER_BOARD = 1 << 1;
+        /// The board is removable.
+        const REMOVABLE = 1 << 2;
+        /// The board is replaceable.
+        const REPLACEABLE = 1 << 3;
+        /// The board is hot swappable.
+        const HOT_SWAPPABLE = 1 << 4;
+    }
+}
+
+spec_enum! {
+    /// Type of a board.
+    pub enum BoardType: u8 {
+        Unknown = 0x01 => "Unknown",
+        Other = 0x02 => "Other",
+        ServerBlade = 0x03 => "Server Blade",
+        ConnectivitySwitch = 0x04 => "Connectivity Switch",
+        SystemManagementModule = 0x05 => "System Management Module",
+        ProcessorModule = 0x06 => "Processor Module",
+        IoModule = 0x07 => "I/O Module",
+        MemoryModule = 0x08 => "Memory Module",
+        DaughterBoard = 0x09 => "Daughter board",
+        Motherboard = 0x0A => "Motherboard (includes processor, memory, and I/O)",
+        ProcessorMemoryModule = 0x0B => "Processor/Memory Module",
+        ProcessorIoModule = 0x0C => "Processor/IO Module",
+        InterconnectBoard = 0x0D => "Interconnect board",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use table::TestStructure;
+
+    #[test]
+    fn contained_objects_truncated() {
+        // Claims 4 handles, but the formatted area only holds 2 and a half.
+        let data = TestStructure::new(2, 0x14)
+            .field(0x0D, &[0x0A, 4])
+            .field(0x0F, &[0x00, 0x04, 0x01, 0x04, 0x02]);
+        let board = BaseboardInformationView::new(data.raw()).unwrap();
+
+        let mut handles = board.contained_object_handles();
+        assert_eq!(handles.len(), 2);
+        assert_eq!(handles.next(), Some(0x0400));
+        assert_eq!(handles.next(), Some(0x0401));
+        assert_eq!(handles.next(), None);
+    }
+}
@@ -0,0 +1,192 @@
+//! BIOS information (type 0).
+
+use table::RawStructure;
+use {BiosCharacteristics, BiosExtendedCharacteristics, SmbiosVersion, Type};
+
+/// Safe view of a BIOS information structure.
+///
+/// Fields which were added by later versions of the spec are returned as `Option`s,
+/// being `None` if the structure is too short to contain them.
+///
+/// Before SMBIOS 2.4, the characteristics extension bytes run until the end of
+/// the formatted area. Fields which come after them therefore need the `SmbiosVersion`
+/// of the table to be located.
+#[derive(Debug, Copy, Clone)]
+pub struct BiosInformationView<'a> {
+    raw: RawStructure<'a>,
+}
+
+impl<'a> BiosInformationView<'a> {
+    /// Interprets a raw structure as BIOS information.
+    ///
+    /// Returns `None` if the structure has a different type or is too short.
+    pub fn new(raw: RawStructure<'a>) -> Option<Self> {
+        if raw.ty() != Type::BiosInformation || raw.formatted().len() < 0x12 {
+            return None;
+        }
+        Some(BiosInformationView { raw })
+    }
+
+    /// The underlying structure.
+    pub fn raw(&self) -> RawStructure<'a> {
+        self.raw
+    }
+
+    /// BIOS vendor.
+    pub fn vendor(&self) -> Option<&'a str> {
+        self.raw.string_lossy(0x04)
+    }
+
+    /// Free-form BIOS version.
+    pub fn version(&self) -> Option<&'a str> {
+        self.raw.string_lossy(0x05)
+    }
+
+    /// The segment of the BIOS's starting address.
+    ///
+    /// This is 0 on UEFI systems.
+    pub fn starting_address_segment(&self) -> u16 {
+        self.raw.word(0x06).unwrap_or(0)
+    }
+
+    /// Release date of BIOS, in mm/dd/yyyy format.
+    pub fn release_date(&self) -> Option<&'a str> {
+        self.raw.string_lossy(0x08)
+    }
+
+    /// Size of the BIOS ROM, in bytes.
+    ///
+    /// Before SMBIOS 3.1, a size byte of 0xFF means exactly 16 MiB. Starting with 3.1,
+    /// it means the size is read from the extended BIOS ROM size field instead.
+    /// Returns `None` if that field is missing or uses a reserved unit.
+    pub fn rom_size(&self, version: SmbiosVersion) -> Option<u64> {
+        match self.raw.byte(0x09).unwrap_or(0) {
+            0xFF if version >= SmbiosVersion::new(3, 1, 0) => {
+                self.extended_rom_size(version).and_then(|size| {
+                    let value = u64::from(size & 0x3FFF);
+                    match size >> 14 {
+                        0b00 => Some(value << 20),
+                        0b01 => Some(value << 30),
+                        _ => None,
+                    }
+                })
+            }
+            n => Some((u64::from(n) + 1) << 16),
+        }
+    }
+
+    /// BIOS characteristics.
+    pub fn characteristics(&self) -> BiosCharacteristics {
+        BiosCharacteristics::from_bits_truncate(self.raw.qword(0x0A).unwrap_or(0))
+    }
+
+    /// The raw characteristics extension bytes.
+    ///
+    /// SMBIOS 2.4+ defines exactly 2 bytes, older versions can have any number of them.
+    pub fn extension_bytes(&self, version: SmbiosVersion) -> &'a [u8] {
+        let formatted = self.raw.formatted();
+        let end = match self.tail_offset(version) {
+            Some(offset) => offset.min(formatted.len()),
+            None => formatted.len(),
+        };
+        &formatted[0x12..end]
+    }
+
+    /// Extended BIOS characteristics, decoded from the extension bytes.
+    pub fn extended_characteristics(
+        &self,
+        version: SmbiosVersion,
+    ) -> Option<BiosExtendedCharacteristics> {
+        let bytes = self.extension_bytes(version);
+        let first = *bytes.first()?;
+        let second = bytes.get(1).cloned().unwrap_or(0);
+        let bits = u16::from(first) | u16::from(second) << 8;
+        Some(BiosExtendedCharacteristics::from_bits_truncate(bits))
+    }
+
+    /// System BIOS release, in major / minor format.
+    ///
+    /// Only supported by SMBIOS 2.4+, and `None` if the BIOS does not report it.
+    pub fn bios_release(&self, version: SmbiosVersion) -> Option<(u8, u8)> {
+        self.release_at(self.tail_offset(version)?)
+    }
+
+    /// Embedded controller firmware release, in major / minor format.
+    ///
+    /// Only supported by SMBIOS 2.4+, and `None` if there is no embedded controller.
+    pub fn ec_release(&self, version: SmbiosVersion) -> Option<(u8, u8)> {
+        self.release_at(self.tail_offset(version)? + 2)
+    }
+
+    /// Raw extended BIOS ROM size.
+    ///
+    /// Only supported by SMBIOS 3.1+. See `rom_size` for the decoded value.
+    pub fn extended_rom_size(&self, version: SmbiosVersion) -> Option<u16> {
+        if version < SmbiosVersion::new(3, 1, 0) {
+            return None;
+        }
+        self.raw.word(self.tail_offset(version)? + 4)
+    }
+
+    /// Offset of the fields following the extension bytes.
+    ///
+    /// Only SMBIOS 2.4+ fixes the number of extension bytes.
+    fn tail_offset(&self, version: SmbiosVersion) -> Option<usize> {
+        if version >= SmbiosVersion::new(2, 4, 0) {
+            Some(0x14)
+        } else {
+            None
+        }
+    }
+
+    fn release_at(&self, offset: usize) -> Option<(u8, u8)> {
+        let bytes = self.raw.bytes(offset, 2)?;
+        match (bytes[0], bytes[1]) {
+            (0xFF, 0xFF) => None,
+            release => Some(release),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use table::TestStructure;
+
+    /// Builds a BIOS information structure with `len - 0x12` extension bytes set to 0x11.
+    fn bios(len: u8) -> TestStructure {
+        TestStructure::new(0, len)
+            .field(0x09, &[0xFF])
+            .field(0x12, &[0x11; 0x100][..len as usize - 0x12])
+    }
+
+    #[test]
+    fn extension_bytes_before_2_4() {
+        let data = bios(0x16);
+        let bios = BiosInformationView::new(data.raw()).unwrap();
+        let version = SmbiosVersion::new(2, 3, 0);
+        assert_eq!(bios.extension_bytes(version), &[0x11; 4]);
+        assert_eq!(bios.bios_release(version), None);
+        assert_eq!(bios.ec_release(version), None);
+        assert_eq!(bios.rom_size(version), Some(16 << 20));
+    }
+
+    #[test]
+    fn release_since_2_4() {
+        let data = bios(0x16);
+        let bios = BiosInformationView::new(data.raw()).unwrap();
+        let version = SmbiosVersion::new(2, 4, 0);
+        assert_eq!(bios.extension_bytes(version), &[0x11; 2]);
+        assert_eq!(bios.bios_release(version), Some((0x11, 0x11)));
+        assert_eq!(bios.ec_release(version), None);
+    }
+
+    #[test]
+    fn extended_rom_size_since_3_1() {
+        let data = bios(0x1A).field(0x18, &[0x20, 0x40]);
+        let bios = BiosInformationView::new(data.raw()).unwrap();
+        assert_eq!(bios.extended_rom_size(SmbiosVersion::new(3, 0, 0)), None);
+        assert_eq!(bios.rom_size(SmbiosVersion::new(3, 0, 0)), Some(16 << 20));
+        assert_eq!(bios.rom_size(SmbiosVersion::new(3, 1, 0)), Some(32 << 30));
+    }
+}
@@ -0,0 +1,205 @@
+//! BIOS language information (type 13).
+
+use strings::{StringSet, StringsLossy};
+use table::RawStructure;
+use Type;
+
+/// Safe view of a BIOS language information structure.
+#[derive(Debug, Copy, Clone)]
+pub struct BiosLanguageInformationView<'a> {
+    raw: RawStructure<'a>,
+}
+
+impl<'a> BiosLanguageInformationView<'a> {
+    /// Interprets a raw structure as BIOS language information.
+    ///
+    /// Returns `None` if the structure has a different type or is too short.
+    pub fn new(raw: RawStructure<'a>) -> Option<Self> {
+        if raw.ty() != Type::BiosLanguageInformation || raw.formatted().len() < 0x16 {
+            return None;
+        }
+        Some(BiosLanguageInformationView { raw })
+    }
+
+    /// The underlying structure.
+    pub fn raw(&self) -> RawStructure<'a> {
+        self.raw
+    }
+
+    /// Number of languages which can be installed.
+    pub fn installable_languages(&self) -> u8 {
+        self.raw.byte(0x04).unwrap_or(0)
+    }
+
+    /// Returns true if the language strings use the abbreviated format, such as `enUS`.
+    ///
+    /// Otherwise, they use the long format, such as `en|US|iso8859-1`.
+    /// The abbreviated format is only supported by SMBIOS 2.1+.
+    pub fn is_abbreviated(&self) -> bool {
+        self.raw.byte(0x05).unwrap_or(0) & 1 != 0
+    }
+
+    /// The language currently in use.
+    pub fn current_language(&self) -> Option<&'a str> {
+        self.raw.string_lossy(0x15)
+    }
+
+    /// The strings of the installable languages.
+    pub fn language_strings(&self) -> StringSet<'a> {
+        self.raw
+            .strings()
+            .take(self.installable_languages() as usize)
+    }
+
+    /// Returns an iterator over the installable languages.
+    pub fn languages(&self) -> Languages<'a> {
+        Languages {
+            strings: self.language_strings().iter_lossy(),
+            abbreviated: self.is_abbreviated(),
+        }
+    }
+}
+
+/// Iterator over the installable languages of the BIOS.
+#[derive(Debug, Clone)]
+pub struct Languages<'a> {
+    strings: StringsLossy<'a>,
+    abbreviated: bool,
+}
+
+impl<'a> Iterator for Languages<'a> {
+    type Item = Language<'a>;
+
+    fn next(&mut self) -> Option<Language<'a>> {
+        let string = self.strings.next()?;
+        if self.abbreviated {
+            Some(Language::parse_abbreviated(string))
+        } else {
+            Some(Language::parse(string))
+        }
+    }
+}
+
+/// A language supported by the BIOS.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Language<'a> {
+    /// ISO 639-1 language code, such as `en`.
+    pub language: &'a str,
+    /// ISO 3166-1 territory code, such as `US`.
+    pub territory: Option<&'a str>,
+    /// Character encoding, such as `iso8859-1`.
+    pub encoding: Option<&'a str>,
+}
+
+impl<'a> Language<'a> {
+    /// Parses a language string in the long format, such as `en|US|iso8859-1`.
+    pub fn parse(string: &'a str) -> Self {
+        let mut parts = string.splitn(3, '|');
+        Language {
+            language: parts.next().unwrap_or(""),
+            territory: parts.next(),
+            encoding: parts.next(),
+        }
+    }
+
+    /// Parses a language string in the abbreviated format, such as `enUS`.
+    pub fn parse_abbreviated(string: &'a str) -> Self {
+        if string.len() > 2 && string.is_char_boundary(2) {
+            Language {
+                language: &string[..2],
+                territory: Some(&string[2..]),
+                encoding: None,
+            }
+        } else {
+            Language {
+                language: string,
+                territory: None,
+                encoding: None,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use table::TestStructure;
+
+    #[test]
+    fn parse_long() {
+        assert_eq!(
+            Language::parse("en|US|iso8859-1"),
+            Language {
+                language: "en",
+                territory: Some("US"),
+                encoding: Some("iso8859-1"),
+            }
+        );
+        assert_eq!(
+            Language::parse("fr|CA"),
+            Language {
+                language: "fr",
+                territory: Some("CA"),
+                encoding: None,
+            }
+        );
+        assert_eq!(
+            Language::parse("de"),
+            Language {
+                language: "de",
+                territory: None,
+                encoding: None,
+            }
+        );
+    }
+
+    #[test]
+    fn parse_abbreviated() {
+        assert_eq!(
+            Language::parse_abbreviated("enUS"),
+            Language {
+                language: "en",
+                territory: Some("US"),
+                encoding: None,
+            }
+        );
+        assert_eq!(Language::parse_abbreviated("en").territory, None);
+    }
+
+    #[test]
+    fn languages() {
+        let data = TestStructure::new(13, 0x16)
+            .field(0x04, &[2])
+            .field(0x15, &[2])
+            .strings(&[b"en|US|iso8859-1", b"fr|FR|iso8859-1", b"extra"]);
+        let bios = BiosLanguageInformationView::new(data.raw()).unwrap();
+        assert!(!bios.is_abbreviated());
+        assert_eq!(bios.current_language(), Some("fr|FR|iso8859-1"));
+
+        let mut languages = bios.languages();
+        assert_eq!(languages.next().unwrap().encoding, Some("iso8859-1"));
+        assert_eq!(languages.next().unwrap().territory, Some("FR"));
+        assert_eq!(languages.next(), None);
+    }
+
+    #[test]
+    fn languages_abbreviated() {
+        let data = TestStructure::new(13, 0x16)
+            .field(0x04, &[1, 0x01])
+            .field(0x15, &[1])
+            .strings(&[b"enUS"]);
+        let bios = BiosLanguageInformationView::new(data.raw()).unwrap();
+        assert!(bios.is_abbreviated());
+
+        let mut languages = bios.languages();
+        assert_eq!(
+            languages.next(),
+            Some(Language {
+                language: "en",
+                territory: Some("US"),
+                encoding: None,
+            })
+        );
+        assert_eq!(languages.next(), None);
+    }
+}
@@ -0,0 +1,257 @@
+//! Cache information (type 7).
+
+use table::RawStructure;
+use Type;
+
+/// Safe view of a cache information structure.
+#[derive(Debug, Copy, Clone)]
+pub struct CacheInformationView<'a> {
+    raw: RawStructure<'a>,
+}
+
+impl<'a> CacheInformationView<'a> {
+    /// Interprets a raw structure as cache information.
+    ///
+    /// Returns `None` if the structure has a different type or is too short.
+    pub fn new(raw: RawStructure<'a>) -> Option<Self> {
+        if raw.ty() != Type::CacheInformation || raw.formatted().len() < 0x0F {
+            return None;
+        }
+        Some(CacheInformationView { raw })
+    }
+
+    /// The underlying structure.
+    pub fn raw(&self) -> RawStructure<'a> {
+        self.raw
+    }
+
+    /// Designation of the cache's socket, as printed on the board.
+    pub fn socket_designation(&self) -> Option<&'a str> {
+        self.raw.string_lossy(0x04)
+    }
+
+    /// Configuration of the cache.
+    pub fn configuration(&self) -> CacheConfiguration {
+        CacheConfiguration(self.raw.word(0x05).unwrap_or(0))
+    }
+
+    /// Maximum size of cache which can be installed, in bytes.
+    ///
+    /// Sizes of 2 GiB or more are read from the Maximum Cache Size 2 field of SMBIOS 3.1+.
+    pub fn max_size(&self) -> u64 {
+        self.size(0x07, 0x13)
+    }
+
+    /// Size of the installed cache, in bytes.
+    ///
+    /// This is 0 if no cache is installed.
+    /// Sizes of 2 GiB or more are read from the Installed Cache Size 2 field of SMBIOS 3.1+.
+    pub fn installed_size(&self) -> u64 {
+        self.size(0x09, 0x17)
+    }
+
+    /// SRAM types supported by the cache.
+    pub fn supported_sram_types(&self) -> SramTypes {
+        SramTypes::from_bits_truncate(self.raw.word(0x0B).unwrap_or(0))
+    }
+
+    /// SRAM type currently used by the cache.
+    pub fn current_sram_type(&self) -> SramTypes {
+        SramTypes::from_bits_truncate(self.raw.word(0x0D).unwrap_or(0))
+    }
+
+    /// Speed of the cache module, in nanoseconds.
+    ///
+    /// Only supported by SMBIOS 2.1+, and `None` if the speed is unknown.
+    pub fn speed(&self) -> Option<u8> {
+        self.raw.byte(0x0F).and_then(|speed| match speed {
+            0 => None,
+            speed => Some(speed),
+        })
+    }
+
+    /// Error correction scheme supported by the cache.
+    ///
+    /// Only supported by SMBIOS 2.1+.
+    pub fn error_correction_type(&self) -> Option<ErrorCorrectionType> {
+        self.raw.byte(0x10).map(ErrorCorrectionType::from)
+    }
+
+    /// Logical type of the cache.
+    ///
+    /// Only supported by SMBIOS 2.1+.
+    pub fn system_cache_type(&self) -> Option<SystemCacheType> {
+        self.raw.byte(0x11).map(SystemCacheType::from)
+    }
+
+    /// Associativity of the cache.
+    ///
+    /// Only supported by SMBIOS 2.1+.
+    pub fn associativity(&self) -> Option<CacheAssociativity> {
+        self.raw.byte(0x12).map(CacheAssociativity::from)
+    }
+
+    fn size(&self, offset: usize, offset2: usize) -> u64 {
+        let size = self.raw.word(offset).unwrap_or(0);
+        match self.raw.dword(offset2) {
+            Some(size2) if size == 0xFFFF => {
+                let granularity = if size2 & (1 << 31) != 0 { 64 } else { 1 };
+                u64::from(size2 & 0x7FFF_FFFF) * granularity * 1024
+            }
+            _ => {
+                let granularity = if size & (1 << 15) != 0 { 64 } else { 1 };
+                u64::from(size & 0x7FFF) * granularity * 1024
+            }
+        }
+    }
+}
+
+/// Configuration of a cache.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct CacheConfiguration(pub u16);
+
+impl CacheConfiguration {
+    /// Level of the cache, starting from 1.
+    pub fn level(&self) -> u8 {
+        (self.0 & 0x7) as u8 + 1
+    }
+
+    /// Returns true if the cache is socketed.
+    pub fn socketed(&self) -> bool {
+        self.0 & (1 << 3) != 0
+    }
+
+    /// Location of the cache, relative to the processor module.
+    pub fn location(&self) -> CacheLocation {
+        CacheLocation::from(((self.0 >> 5) & 0x3) as u8)
+    }
+
+    /// Returns true if the cache was enabled at boot time.
+    pub fn enabled(&self) -> bool {
+        self.0 & (1 << 7) != 0
+    }
+
+    /// Operational mode of the cache.
+    pub fn operational_mode(&self) -> CacheOperationalMode {
+        CacheOperationalMode::from(((self.0 >> 8) & 0x3) as u8)
+    }
+}
+
+bitflags! {
+    /// Types of SRAM used by a cache.
+    pub struct SramTypes: u16 {
+        /// Other.
+        const OTHER = 1 << 0;
+        /// Unknown.
+        const UNKNOWN = 1 << 1;
+        /// Non-burst.
+        const NON_BURST = 1 << 2;
+        /// Burst.
+        const BURST = 1 << 3;
+        /// Pipeline burst.
+        const PIPELINE_BURST = 1 << 4;
+        /// Synchronous.
+        const SYNCHRONOUS = 1 << 5;
+        /// Asynchronous.
+        const ASYNCHRONOUS = 1 << 6;
+    }
+}
+
+spec_enum! {
+    /// Location of a cache, relative to the processor module.
+    pub enum CacheLocation: u8 {
+        Internal = 0x00 => "Internal",
+        External = 0x01 => "External",
+        Reserved = 0x02 => "Reserved",
+        Unknown = 0x03 => "Unknown",
+    }
+}
+
+spec_enum! {
+    /// Write policy of a cache.
+    pub enum CacheOperationalMode: u8 {
+        WriteThrough = 0x00 => "Write Through",
+        WriteBack = 0x01 => "Write Back",
+        VariesWithMemoryAddress = 0x02 => "Varies with Memory Address",
+        Unknown = 0x03 => "Unknown",
+    }
+}
+
+spec_enum! {
+    /// Error correction scheme supported by a cache.
+    pub enum ErrorCorrectionType: u8 {
+        Other = 0x01 => "Other",
+        Unknown = 0x02 => "Unknown",
+        None = 0x03 => "None",
+        Parity = 0x04 => "Parity",
+        SingleBitEcc = 0x05 => "Single-bit ECC",
+        MultiBitEcc = 0x06 => "Multi-bit ECC",
+    }
+}
+
+spec_enum! {
+    /// Logical type of a cache.
+    pub enum SystemCacheType: u8 {
+        Other = 0x01 => "Other",
+        Unknown = 0x02 => "Unknown",
+        Instruction = 0x03 => "Instruction",
+        Data = 0x04 => "Data",
+        Unified = 0x05 => "Unified",
+    }
+}
+
+spec_enum! {
+    /// Associativity of a cache.
+    pub enum CacheAssociativity: u8 {
+        Other = 0x01 => "Other",
+        Unknown = 0x02 => "Unknown",
+        DirectMapped = 0x03 => "Direct Mapped",
+        TwoWay = 0x04 => "2-way Set-Associative",
+        FourWay = 0x05 => "4-way Set-Associative",
+        FullyAssociative = 0x06 => "Fully Associative",
+        EightWay = 0x07 => "8-way Set-Associative",
+        SixteenWay = 0x08 => "16-way Set-Associative",
+        TwelveWay = 0x09 => "12-way Set-Associative",
+        TwentyFourWay = 0x0A => "24-way Set-Associative",
+        ThirtyTwoWay = 0x0B => "32-way Set-Associative",
+        FortyEightWay = 0x0C => "48-way Set-Associative",
+        SixtyFourWay = 0x0D => "64-way Set-Associative",
+        TwentyWay = 0x0E => "20-way Set-Associative",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use table::TestStructure;
+
+    #[test]
+    fn size_granularity() {
+        // 512 KiB in 1K units, and 8 MiB in 64K units.
+        let data = TestStructure::new(7, 0x13)
+            .field(0x07, &[0x00, 0x02])
+            .field(0x09, &[0x80, 0x80]);
+        let cache = CacheInformationView::new(data.raw()).unwrap();
+        assert_eq!(cache.max_size(), 512 << 10);
+        assert_eq!(cache.installed_size(), 8 << 20);
+    }
+
+    #[test]
+    fn size_2() {
+        // 4 GiB, in 1K units and in 64K units.
+        let data = TestStructure::new(7, 0x1B)
+            .field(0x07, &[0xFF, 0xFF, 0xFF, 0xFF])
+            .field(0x13, &[0x00, 0x00, 0x40, 0x00])
+            .field(0x17, &[0x00, 0x00, 0x01, 0x80]);
+        let cache = CacheInformationView::new(data.raw()).unwrap();
+        assert_eq!(cache.max_size(), 4 << 30);
+        assert_eq!(cache.installed_size(), 4 << 30);
+    }
+
+    #[test]
+    fn size_2_missing() {
+        let data = TestStructure::new(7, 0x13).field(0x07, &[0xFF, 0xFF]);
+        let cache = CacheInformationView::new(data.raw()).unwrap();
+        assert_eq!(cache.max_size(), 0x7FFF * (64 << 10));
+    }
+}
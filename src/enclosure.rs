@@ -0,0 +1,301 @@
+//! System enclosure or chassis (type 3).
+
+use baseboard::BoardType;
+use table::RawStructure;
+use Type;
+
+/// Safe view of a system enclosure structure.
+#[derive(Debug, Copy, Clone)]
+pub struct SystemEnclosureView<'a> {
+    raw: RawStructure<'a>,
+}
+
+impl<'a> SystemEnclosureView<'a> {
+    /// Interprets a raw structure as a system enclosure.
+    ///
+    /// Returns `None` if the structure has a different type or is too short.
+    pub fn new(raw: RawStructure<'a>) -> Option<Self> {
+        if raw.ty() != Type::SystemEnclosure || raw.formatted().len() < 0x09 {
+            return None;
+        }
+        Some(SystemEnclosureView { raw })
+    }
+
+    /// The underlying structure.
+    pub fn raw(&self) -> RawStructure<'a> {
+        self.raw
+    }
+
+    /// Chassis manufacturer.
+    pub fn manufacturer(&self) -> Option<&'a str> {
+        self.raw.string_lossy(0x04)
+    }
+
+    /// Type of the chassis.
+    pub fn chassis_type(&self) -> ChassisType {
+        ChassisType::from(self.raw.byte(0x05).unwrap_or(0) & 0x7F)
+    }
+
+    /// Returns true if the chassis has a lock.
+    pub fn lock_present(&self) -> bool {
+        self.raw.byte(0x05).unwrap_or(0) & 0x80 != 0
+    }
+
+    /// Chassis version.
+    pub fn version(&self) -> Option<&'a str> {
+        self.raw.string_lossy(0x06)
+    }
+
+    /// Chassis serial number.
+    pub fn serial_number(&self) -> Option<&'a str> {
+        self.raw.string_lossy(0x07)
+    }
+
+    /// Chassis asset tag.
+    pub fn asset_tag(&self) -> Option<&'a str> {
+        self.raw.string_lossy(0x08)
+    }
+
+    /// State of the enclosure when it was last booted.
+    ///
+    /// Only supported by SMBIOS 2.1+.
+    pub fn boot_up_state(&self) -> Option<ChassisState> {
+        self.raw.byte(0x09).map(ChassisState::from)
+    }
+
+    /// State of the enclosure's power supply when it was last booted.
+    ///
+    /// Only supported by SMBIOS 2.1+.
+    pub fn power_supply_state(&self) -> Option<ChassisState> {
+        self.raw.byte(0x0A).map(ChassisState::from)
+    }
+
+    /// Thermal state of the enclosure when it was last booted.
+    ///
+    /// Only supported by SMBIOS 2.1+.
+    pub fn thermal_state(&self) -> Option<ChassisState> {
+        self.raw.byte(0x0B).map(ChassisState::from)
+    }
+
+    /// Physical security status of the enclosure when it was last booted.
+    ///
+    /// Only supported by SMBIOS 2.1+.
+    pub fn security_status(&self) -> Option<SecurityStatus> {
+        self.raw.byte(0x0C).map(SecurityStatus::from)
+    }
+
+    /// OEM or BIOS vendor-specific information.
+    ///
+    /// Only supported by SMBIOS 2.3+.
+    pub fn oem_defined(&self) -> Option<u32> {
+        self.raw.dword(0x0D)
+    }
+
+    /// Height of the enclosure, in rack units (1U is 1.75 inches).
+    ///
+    /// Returns `None` if the height is unspecified.
+    pub fn height(&self) -> Option<u8> {
+        self.raw.byte(0x11).and_then(non_zero)
+    }
+
+    /// Number of power cords associated with the enclosure.
+    ///
+    /// Returns `None` if the number is unspecified.
+    pub fn power_cords(&self) -> Option<u8> {
+        self.raw.byte(0x12).and_then(non_zero)
+    }
+
+    /// Returns an iterator over the elements contained in the chassis.
+    pub fn contained_elements(&self) -> ContainedElements<'a> {
+        let count = self.raw.byte(0x13).unwrap_or(0) as usize;
+        let record_len = self.raw.byte(0x14).unwrap_or(0) as usize;
+
+        let formatted = self.raw.formatted();
+        let start = 0x15.min(formatted.len());
+        let end = (0x15 + count * record_len).min(formatted.len());
+
+        ContainedElements {
+            bytes: &formatted[start..end],
+            record_len,
+        }
+    }
+
+    /// Stock keeping unit of the chassis.
+    ///
+    /// Only supported by SMBIOS 2.7+. It follows the contained element records.
+    pub fn sku_number(&self) -> Option<&'a str> {
+        let count = self.raw.byte(0x13)? as usize;
+        let record_len = self.raw.byte(0x14)? as usize;
+        self.raw.string_lossy(0x15 + count * record_len)
+    }
+}
+
+fn non_zero(value: u8) -> Option<u8> {
+    if value == 0 {
+        None
+    } else {
+        Some(value)
+    }
+}
+
+/// Iterator over the elements contained in a chassis.
+#[derive(Debug, Clone)]
+pub struct ContainedElements<'a> {
+    bytes: &'a [u8],
+    record_len: usize,
+}
+
+impl<'a> Iterator for ContainedElements<'a> {
+    type Item = ContainedElement;
+
+    fn next(&mut self) -> Option<ContainedElement> {
+        // Records shorter than the ones defined by the spec can't be decoded.
+        if self.record_len < 3 || self.bytes.len() < self.record_len {
+            return None;
+        }
+
+        let record = &self.bytes[..self.record_len];
+        self.bytes = &self.bytes[self.record_len..];
+
+        let ty = if record[0] & 0x80 != 0 {
+            ContainedElementType::Structure(Type::from(record[0] & 0x7F))
+        } else {
+            ContainedElementType::Board(BoardType::from(record[0]))
+        };
+
+        Some(ContainedElement {
+            ty,
+            minimum: record[1],
+            maximum: record[2],
+        })
+    }
+}
+
+/// An element which can be contained in a chassis.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct ContainedElement {
+    /// Type of the element.
+    pub ty: ContainedElementType,
+    /// Minimum number of elements of this type in the chassis.
+    pub minimum: u8,
+    /// Maximum number of elements of this type in the chassis.
+    pub maximum: u8,
+}
+
+/// Type of an element contained in a chassis.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ContainedElementType {
+    /// A board of the given type.
+    Board(BoardType),
+    /// A structure of the given type, such as a power supply.
+    Structure(Type),
+}
+
+spec_enum! {
+    /// Type of a system enclosure.
+    pub enum ChassisType: u8 {
+        Other = 0x01 => "Other",
+        Unknown = 0x02 => "Unknown",
+        Desktop = 0x03 => "Desktop",
+        LowProfileDesktop = 0x04 => "Low Profile Desktop",
+        PizzaBox = 0x05 => "Pizza Box",
+        MiniTower = 0x06 => "Mini Tower",
+        Tower = 0x07 => "Tower",
+        Portable = 0x08 => "Portable",
+        Laptop = 0x09 => "Laptop",
+        Notebook = 0x0A => "Notebook",
+        HandHeld = 0x0B => "Hand Held",
+        DockingStation = 0x0C => "Docking Station",
+        AllInOne = 0x0D => "All in One",
+        SubNotebook = 0x0E => "Sub Notebook",
+        SpaceSaving = 0x0F => "Space-saving",
+        LunchBox = 0x10 => "Lunch Box",
+        MainServerChassis = 0x11 => "Main Server Chassis",
+        ExpansionChassis = 0x12 => "Expansion Chassis",
+        SubChassis = 0x13 => "SubChassis",
+        BusExpansionChassis = 0x14 => "Bus Expansion Chassis",
+        PeripheralChassis = 0x15 => "Peripheral Chassis",
+        RaidChassis = 0x16 => "RAID Chassis",
+        RackMountChassis = 0x17 => "Rack Mount Chassis",
+        SealedCasePc = 0x18 => "Sealed-case PC",
+        MultiSystemChassis = 0x19 => "Multi-system chassis",
+        CompactPci = 0x1A => "Compact PCI",
+        AdvancedTca = 0x1B => "Advanced TCA",
+        Blade = 0x1C => "Blade",
+        BladeEnclosure = 0x1D => "Blade Enclosure",
+        Tablet = 0x1E => "Tablet",
+        Convertible = 0x1F => "Convertible",
+        Detachable = 0x20 => "Detachable",
+        IotGateway = 0x21 => "IoT Gateway",
+        EmbeddedPc = 0x22 => "Embedded PC",
+        MiniPc = 0x23 => "Mini PC",
+        StickPc = 0x24 => "Stick PC",
+    }
+}
+
+spec_enum! {
+    /// State of a system enclosure, its power supply or its thermal condition.
+    pub enum ChassisState: u8 {
+        Other = 0x01 => "Other",
+        Unknown = 0x02 => "Unknown",
+        Safe = 0x03 => "Safe",
+        Warning = 0x04 => "Warning",
+        Critical = 0x05 => "Critical",
+        NonRecoverable = 0x06 => "Non-recoverable",
+    }
+}
+
+spec_enum! {
+    /// Physical security status of a system enclosure.
+    pub enum SecurityStatus: u8 {
+        Other = 0x01 => "Other",
+        Unknown = 0x02 => "Unknown",
+        None = 0x03 => "None",
+        ExternalInterfaceLockedOut = 0x04 => "External interface locked out",
+        ExternalInterfaceEnabled = 0x05 => "External interface enabled",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use table::StructureTable;
+
+    #[test]
+    fn sku_after_contained_elements() {
+        let mut data = [0; 0x1C + 12];
+        data[..0x04].copy_from_slice(&[3, 0x1C, 0x00, 0x03]);
+        data[0x04] = 1;
+        data[0x05] = 0x17;
+        // Two elements of 3 bytes each: a motherboard and power supplies.
+        data[0x13] = 2;
+        data[0x14] = 3;
+        data[0x15..0x1B].copy_from_slice(&[0x0A, 1, 1, 0x80 | 39, 1, 2]);
+        data[0x1B] = 2;
+        data[0x1C..].copy_from_slice(b"Acme\0SKU-1\0\0");
+        let raw = StructureTable::new(&data).iter().next().unwrap().unwrap();
+        let enclosure = SystemEnclosureView::new(raw).unwrap();
+
+        let mut elements = enclosure.contained_elements();
+        assert_eq!(
+            elements.next(),
+            Some(ContainedElement {
+                ty: ContainedElementType::Board(BoardType::Motherboard),
+                minimum: 1,
+                maximum: 1,
+            })
+        );
+        assert_eq!(
+            elements.next(),
+            Some(ContainedElement {
+                ty: ContainedElementType::Structure(Type::SystemPowerSupply),
+                minimum: 1,
+                maximum: 2,
+            })
+        );
+        assert_eq!(elements.next(), None);
+
+        assert_eq!(enclosure.manufacturer(), Some("Acme"));
+        assert_eq!(enclosure.sku_number(), Some("SKU-1"));
+    }
+}
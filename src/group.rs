@@ -0,0 +1,161 @@
+//! Group associations (type 14).
+
+use table::{RawStructure, StructureTable};
+use {read_u16, Type};
+
+/// Safe view of a group associations structure.
+#[derive(Debug, Copy, Clone)]
+pub struct GroupAssociationsView<'a> {
+    raw: RawStructure<'a>,
+}
+
+impl<'a> GroupAssociationsView<'a> {
+    /// Interprets a raw structure as group associations.
+    ///
+    /// Returns `None` if the structure has a different type or is too short.
+    pub fn new(raw: RawStructure<'a>) -> Option<Self> {
+        if raw.ty() != Type::GroupAssociations || raw.formatted().len() < 0x05 {
+            return None;
+        }
+        Some(GroupAssociationsView { raw })
+    }
+
+    /// The underlying structure.
+    pub fn raw(&self) -> RawStructure<'a> {
+        self.raw
+    }
+
+    /// Name of the group.
+    pub fn group_name(&self) -> Option<&'a str> {
+        self.raw.string_lossy(0x04)
+    }
+
+    /// Number of items in the group.
+    pub fn count(&self) -> usize {
+        (self.raw.formatted().len() - 0x05) / 3
+    }
+
+    /// Returns an iterator over the items in the group.
+    pub fn items(&self) -> GroupItems<'a> {
+        let formatted = self.raw.formatted();
+        GroupItems {
+            bytes: &formatted[0x05..0x05 + 3 * self.count()],
+        }
+    }
+}
+
+/// Iterator over the items of a group.
+#[derive(Debug, Clone)]
+pub struct GroupItems<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Iterator for GroupItems<'a> {
+    type Item = GroupItem;
+
+    fn next(&mut self) -> Option<GroupItem> {
+        if self.bytes.len() < 3 {
+            return None;
+        }
+
+        let item = GroupItem {
+            ty: Type::from(self.bytes[0]),
+            handle: read_u16(self.bytes, 1),
+        };
+        self.bytes = &self.bytes[3..];
+
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.bytes.len() / 3;
+        (len, Some(len))
+    }
+}
+
+impl<'a> ExactSizeIterator for GroupItems<'a> {}
+
+/// A structure which is a member of a group.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct GroupItem {
+    /// Type of the structure.
+    pub ty: Type,
+    /// Handle of the structure.
+    pub handle: u16,
+}
+
+impl GroupItem {
+    /// Finds the structure referenced by this item in `table`.
+    ///
+    /// Returns `None` if there is no structure with this handle,
+    /// or if it does not have the expected type.
+    pub fn resolve<'a>(&self, table: &StructureTable<'a>) -> Option<RawStructure<'a>> {
+        table
+            .find_by_handle(self.handle)
+            .filter(|structure| structure.ty() == self.ty)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use self::std::vec::Vec;
+    use super::*;
+    use table::TestStructure;
+
+    #[test]
+    fn items() {
+        // The trailing 2 bytes do not make up a whole item.
+        let data = TestStructure::new(14, 0x0D)
+            .field(0x04, &[1, 0x00, 0x00, 0x01, 0x04, 0x34, 0x12, 0x11])
+            .strings(&[b"Firmware Version Info"]);
+        let group = GroupAssociationsView::new(data.raw()).unwrap();
+        assert_eq!(group.group_name(), Some("Firmware Version Info"));
+        assert_eq!(group.count(), 2);
+
+        let mut items = group.items();
+        assert_eq!(items.len(), 2);
+        assert_eq!(
+            items.next(),
+            Some(GroupItem {
+                ty: Type::BiosInformation,
+                handle: 0x0100,
+            })
+        );
+        assert_eq!(
+            items.next(),
+            Some(GroupItem {
+                ty: Type::ProcessorInformation,
+                handle: 0x1234,
+            })
+        );
+        assert_eq!(items.next(), None);
+    }
+
+    #[test]
+    fn resolve() {
+        let mut data = Vec::new();
+        data.extend_from_slice(TestStructure::new(0, 0x12).handle(0x0100).bytes());
+        data.extend_from_slice(TestStructure::new(127, 4).handle(0xFEFF).bytes());
+        let table = StructureTable::new(&data);
+
+        let item = GroupItem {
+            ty: Type::BiosInformation,
+            handle: 0x0100,
+        };
+        assert_eq!(item.resolve(&table).unwrap().handle(), 0x0100);
+
+        let wrong_type = GroupItem {
+            ty: Type::SystemInformation,
+            handle: 0x0100,
+        };
+        assert!(wrong_type.resolve(&table).is_none());
+
+        let missing = GroupItem {
+            ty: Type::BiosInformation,
+            handle: 0x0200,
+        };
+        assert!(missing.resolve(&table).is_none());
+    }
+}
@@ -5,6 +5,10 @@
 //! The `Smbios2EntryPoint`, despite its name, can be returned by a SMBIOS 3 implementation.
 //! The only difference is that it points to an array which is within the first 4 GiBs.
 //!
+//! Entry points found in memory should be validated with `Smbios2EntryPoint::parse`
+//! or `Smbios3EntryPoint::parse` before their contents are trusted.
+//! On legacy BIOS systems, `find_entry_points` can be used to search for them.
+//!
 //! ## Structure array
 //!
 //! The entry point contains the size and address of an array of structures.
@@ -23,18 +27,86 @@
 //! After this "formatted" area of the SMBIOS structures come a tightly-packed array
 //! of NULL-terminated strings. These strings are referenced by index (for example,
 //! if a structure reports the BIOS' name is "3", it means you need to parse the string
-//! with index 3, counting from 0).
+//! with index 3, counting from 1). An index of 0 means the field has no string.
 //!
 //! At the end of this string area is a double NULL-terminator.
+//! The strings of a structure can be resolved through its `StringSet`.
+//!
+//! The structures can be walked using a `StructureTable`.
 
 #![no_std]
-
 #![deny(missing_docs)]
-#![cfg_attr(feature = "cargo-clippy", deny(clippy))]
+#![deny(clippy::all)]
 
 #[macro_use]
 extern crate bitflags;
 
+use core::fmt;
+use core::mem;
+
+#[macro_use]
+mod macros;
+
+mod baseboard;
+mod bios;
+mod bios_language;
+mod cache;
+mod enclosure;
+mod group;
+mod memory_controller;
+mod memory_module;
+mod oem_strings;
+mod on_board;
+mod port;
+mod processor;
+mod slot;
+mod strings;
+mod system;
+mod system_configuration;
+mod table;
+mod topology;
+
+pub use baseboard::{BaseboardFeatures, BaseboardInformationView, BoardType};
+pub use bios::BiosInformationView;
+pub use bios_language::{BiosLanguageInformationView, Language, Languages};
+pub use cache::{
+    CacheAssociativity, CacheConfiguration, CacheInformationView, CacheLocation,
+    CacheOperationalMode, ErrorCorrectionType, SramTypes, SystemCacheType,
+};
+pub use enclosure::{
+    ChassisState, ChassisType, ContainedElement, ContainedElementType, ContainedElements,
+    SecurityStatus, SystemEnclosureView,
+};
+pub use group::{GroupAssociationsView, GroupItem, GroupItems};
+pub use memory_controller::{
+    ErrorCorrectingCapabilities, ErrorDetectingMethod, Interleave, MemoryControllerInformationView,
+    MemorySpeeds, MemoryTypes,
+};
+pub use memory_module::{MemoryModuleInformationView, ModuleErrorStatus, ModuleSize};
+pub use oem_strings::OemStringsView;
+pub use on_board::{
+    OnBoardDevice, OnBoardDeviceType, OnBoardDevices, OnBoardDevicesInformationView,
+};
+pub use port::{ConnectorType, PortConnectorInformationView, PortType};
+pub use processor::{
+    ArmSocId, DecodedProcessorId, LegacyVoltages, Midr, ProcessorArchitecture,
+    ProcessorCharacteristics, ProcessorFamily, ProcessorId, ProcessorInformationView,
+    ProcessorStatus, ProcessorType, ProcessorUpgrade, ProcessorVoltage, X86Features,
+    X86ProcessorId,
+};
+pub use slot::{
+    PciAddress, PeerDevice, PeerGroups, SlotCharacteristics1, SlotCharacteristics2, SlotHeight,
+    SlotLength, SlotType, SlotUsage, SlotWidth, SystemSlotView,
+};
+pub use strings::{to_str_lossy, StringSet, Strings, StringsLossy};
+pub use system::{SystemInformationView, Uuid, WakeUpType};
+pub use system_configuration::SystemConfigurationOptionsView;
+pub use table::{
+    Handles, LenientStructures, RawStructure, StructureTable, Structures, TableError,
+    TableErrorKind,
+};
+pub use topology::{CacheLink, ProcessorCaches, ProcessorTopology};
+
 /// Entry point available in SMBIOS 2.1+, only supports 32-bit addresses.
 #[derive(Debug, Copy, Clone)]
 #[repr(C, packed)]
@@ -68,6 +140,70 @@ pub struct Smbios2EntryPoint {
     pub bcd_revision: u8,
 }
 
+impl Smbios2EntryPoint {
+    /// Parses an entry point from the start of `bytes`.
+    ///
+    /// Both the `_SM_` and `_DMI_` anchors are checked, as well as the checksum
+    /// of the whole structure and the checksum of the intermediate EPS.
+    pub fn parse(bytes: &[u8]) -> Result<Self, EntryPointError> {
+        if bytes.len() < mem::size_of::<Self>() {
+            return Err(EntryPointError::Truncated);
+        }
+
+        if &bytes[0x00..0x04] != b"_SM_" {
+            return Err(EntryPointError::BadAnchor);
+        }
+
+        // Version 2.1 of the spec incorrectly stated the length to be 0x1E,
+        // and some implementations still report that value.
+        let length = bytes[0x05] as usize;
+        if length < 0x1E {
+            return Err(EntryPointError::BadLength);
+        }
+        if bytes.len() < length {
+            return Err(EntryPointError::Truncated);
+        }
+        if checksum(&bytes[..length]) != 0 {
+            return Err(EntryPointError::BadChecksum);
+        }
+
+        if &bytes[0x10..0x15] != b"_DMI_" {
+            return Err(EntryPointError::BadIntermediateAnchor);
+        }
+        if checksum(&bytes[0x10..0x1F]) != 0 {
+            return Err(EntryPointError::BadIntermediateChecksum);
+        }
+
+        Ok(Smbios2EntryPoint {
+            anchor0: [bytes[0x00], bytes[0x01], bytes[0x02], bytes[0x03]],
+            chksum0: bytes[0x04],
+            length: bytes[0x05],
+            smbios_version: (bytes[0x06], bytes[0x07]),
+            max_size: read_u16(bytes, 0x08),
+            revision: bytes[0x0A],
+            _reserved: [
+                bytes[0x0B],
+                bytes[0x0C],
+                bytes[0x0D],
+                bytes[0x0E],
+                bytes[0x0F],
+            ],
+            anchor1: [
+                bytes[0x10],
+                bytes[0x11],
+                bytes[0x12],
+                bytes[0x13],
+                bytes[0x14],
+            ],
+            chksum1: bytes[0x15],
+            table_size: read_u16(bytes, 0x16),
+            table_addr: read_u32(bytes, 0x18),
+            table_len: read_u16(bytes, 0x1C),
+            bcd_revision: bytes[0x1E],
+        })
+    }
+}
+
 /// Entry point for SMBIOS 3+ structures, supports 64-bit addresses.
 #[derive(Debug, Copy, Clone)]
 #[repr(C, packed)]
@@ -85,12 +221,262 @@ pub struct Smbios3EntryPoint {
     /// Reserved, must be 0.
     pub _reserved: u8,
     /// Max size of table pointed to by `address`, in bytes.
-    pub max_size: u16,
+    pub max_size: u32,
     /// 64-bit physical address of the SMBIOS structures array.
     pub address: u64,
 }
 
+impl Smbios3EntryPoint {
+    /// Parses an entry point from the start of `bytes`.
+    ///
+    /// The `_SM3_` anchor, the checksum and the entry point revision are checked.
+    pub fn parse(bytes: &[u8]) -> Result<Self, EntryPointError> {
+        if bytes.len() < mem::size_of::<Self>() {
+            return Err(EntryPointError::Truncated);
+        }
+
+        if &bytes[0x00..0x05] != b"_SM3_" {
+            return Err(EntryPointError::BadAnchor);
+        }
+
+        let length = bytes[0x06] as usize;
+        if length < mem::size_of::<Self>() {
+            return Err(EntryPointError::BadLength);
+        }
+        if bytes.len() < length {
+            return Err(EntryPointError::Truncated);
+        }
+        if checksum(&bytes[..length]) != 0 {
+            return Err(EntryPointError::BadChecksum);
+        }
+
+        if bytes[0x0A] != 1 {
+            return Err(EntryPointError::BadRevision);
+        }
+
+        Ok(Smbios3EntryPoint {
+            anchor: [
+                bytes[0x00],
+                bytes[0x01],
+                bytes[0x02],
+                bytes[0x03],
+                bytes[0x04],
+            ],
+            chksum: bytes[0x05],
+            length: bytes[0x06],
+            version: (bytes[0x07], bytes[0x08], bytes[0x09]),
+            revision: bytes[0x0A],
+            _reserved: bytes[0x0B],
+            max_size: read_u32(bytes, 0x0C),
+            address: read_u64(bytes, 0x10),
+        })
+    }
+}
+
+/// An entry point of either SMBIOS version.
+#[derive(Debug, Copy, Clone)]
+pub enum EntryPoint {
+    /// A 32-bit entry point.
+    Smbios2(Smbios2EntryPoint),
+    /// A 64-bit entry point.
+    Smbios3(Smbios3EntryPoint),
+}
+
+impl EntryPoint {
+    /// Parses an entry point of any version from the start of `bytes`.
+    ///
+    /// The version is chosen based on the anchor string.
+    pub fn parse(bytes: &[u8]) -> Result<Self, EntryPointError> {
+        if bytes.starts_with(b"_SM3_") {
+            Smbios3EntryPoint::parse(bytes).map(EntryPoint::Smbios3)
+        } else if bytes.starts_with(b"_SM_") {
+            Smbios2EntryPoint::parse(bytes).map(EntryPoint::Smbios2)
+        } else {
+            Err(EntryPointError::BadAnchor)
+        }
+    }
+
+    /// Physical address of the structure table.
+    pub fn table_address(&self) -> u64 {
+        match *self {
+            EntryPoint::Smbios2(ref eps) => u64::from(eps.table_addr),
+            EntryPoint::Smbios3(ref eps) => eps.address,
+        }
+    }
+
+    /// Maximum size of the structure table, in bytes.
+    ///
+    /// For SMBIOS 2 this is the exact size of the table.
+    pub fn table_max_size(&self) -> u32 {
+        match *self {
+            EntryPoint::Smbios2(ref eps) => u32::from(eps.table_size),
+            EntryPoint::Smbios3(ref eps) => eps.max_size,
+        }
+    }
+
+    /// Number of structures in the table.
+    ///
+    /// Only SMBIOS 2 entry points report this, the table of a SMBIOS 3 entry point
+    /// must be walked until the End-of-Table structure is found.
+    pub fn structure_count(&self) -> Option<u16> {
+        match *self {
+            EntryPoint::Smbios2(ref eps) => Some(eps.table_len),
+            EntryPoint::Smbios3(_) => None,
+        }
+    }
+
+    /// Version of the specification the table conforms to.
+    pub fn version(&self) -> SmbiosVersion {
+        match *self {
+            EntryPoint::Smbios2(ref eps) => {
+                let (major, minor) = eps.smbios_version;
+                SmbiosVersion {
+                    major,
+                    minor,
+                    docrev: 0,
+                }
+            }
+            EntryPoint::Smbios3(ref eps) => {
+                let (major, minor, docrev) = eps.version;
+                SmbiosVersion {
+                    major,
+                    minor,
+                    docrev,
+                }
+            }
+        }
+    }
+}
+
+/// Scans a memory window for valid entry points.
+///
+/// On non-UEFI systems the entry point is found in the `0xF0000 - 0xFFFFF` physical
+/// memory range, on a 16-byte boundary. `memory` should therefore start on a 16-byte
+/// aligned address, since anchors are only searched at offsets which are multiples of 16.
+///
+/// Candidates which fail validation are skipped.
+pub fn find_entry_points<'a>(memory: &'a [u8]) -> EntryPoints<'a> {
+    EntryPoints { memory, offset: 0 }
+}
+
+/// Iterator over the entry points found in a memory window.
+///
+/// Yields the offset of each entry point, relative to the start of the window,
+/// together with the parsed entry point.
+#[derive(Debug, Clone)]
+pub struct EntryPoints<'a> {
+    memory: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Iterator for EntryPoints<'a> {
+    type Item = (usize, EntryPoint);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.offset < self.memory.len() {
+            let offset = self.offset;
+            self.offset += 16;
+
+            if let Ok(eps) = EntryPoint::parse(&self.memory[offset..]) {
+                return Some((offset, eps));
+            }
+        }
+
+        None
+    }
+}
+
+/// Version of the SMBIOS specification.
+///
+/// Versions can be compared to check if a field is supported.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct SmbiosVersion {
+    /// Major version.
+    pub major: u8,
+    /// Minor version.
+    pub minor: u8,
+    /// Document revision.
+    ///
+    /// Only reported by SMBIOS 3 entry points, 0 otherwise.
+    pub docrev: u8,
+}
+
+impl SmbiosVersion {
+    /// Creates a new version.
+    pub fn new(major: u8, minor: u8, docrev: u8) -> Self {
+        SmbiosVersion {
+            major,
+            minor,
+            docrev,
+        }
+    }
+}
+
+impl fmt::Display for SmbiosVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.docrev)
+    }
+}
+
+/// Reasons for which an entry point can be rejected.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum EntryPointError {
+    /// The buffer is too small to contain the whole entry point.
+    Truncated,
+    /// The anchor string at the start of the entry point does not match.
+    BadAnchor,
+    /// The `length` field is smaller than the entry point structure.
+    BadLength,
+    /// The bytes of the entry point do not add up to 0.
+    BadChecksum,
+    /// The entry point revision is not supported.
+    BadRevision,
+    /// The anchor string of the intermediate EPS is not "_DMI_".
+    BadIntermediateAnchor,
+    /// The bytes of the intermediate EPS do not add up to 0.
+    BadIntermediateChecksum,
+}
+
+impl fmt::Display for EntryPointError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let msg = match *self {
+            EntryPointError::Truncated => "entry point is truncated",
+            EntryPointError::BadAnchor => "invalid entry point anchor",
+            EntryPointError::BadLength => "invalid entry point length",
+            EntryPointError::BadChecksum => "invalid entry point checksum",
+            EntryPointError::BadRevision => "unsupported entry point revision",
+            EntryPointError::BadIntermediateAnchor => "invalid intermediate anchor",
+            EntryPointError::BadIntermediateChecksum => "invalid intermediate checksum",
+        };
+        f.write_str(msg)
+    }
+}
+
+/// Adds up all the bytes, wrapping on overflow.
+///
+/// A valid SMBIOS structure with a checksum field adds up to 0.
+fn checksum(bytes: &[u8]) -> u8 {
+    bytes.iter().fold(0, |sum, &b| sum.wrapping_add(b))
+}
+
+/// Reads a little-endian `u16` at `offset`.
+fn read_u16(bytes: &[u8], offset: usize) -> u16 {
+    u16::from(bytes[offset]) | u16::from(bytes[offset + 1]) << 8
+}
+
+/// Reads a little-endian `u32` at `offset`.
+fn read_u32(bytes: &[u8], offset: usize) -> u32 {
+    u32::from(read_u16(bytes, offset)) | u32::from(read_u16(bytes, offset + 2)) << 16
+}
+
+/// Reads a little-endian `u64` at `offset`.
+fn read_u64(bytes: &[u8], offset: usize) -> u64 {
+    u64::from(read_u32(bytes, offset)) | u64::from(read_u32(bytes, offset + 4)) << 32
+}
+
 /// A type used to index the string table for each structure.
+///
+/// Strings are numbered from 1, a value of 0 means there is no string.
 pub type StringRef = u8;
 
 /// Common header for all SMBIOS structures.
@@ -98,7 +484,10 @@ pub type StringRef = u8;
 #[repr(C, packed)]
 pub struct Header {
     /// Type of this structure.
-    pub ty: Type,
+    ///
+    /// This is kept as a raw byte, since the firmware can report any value.
+    /// Use `structure_type` to interpret it.
+    pub ty: u8,
     /// Size of the formatted area of the structure, including the header.
     ///
     /// The length of the strings at the end is not included.
@@ -112,33 +501,301 @@ pub struct Header {
     pub handle: u16,
 }
 
+impl Header {
+    /// Type of this structure.
+    pub fn structure_type(&self) -> Type {
+        Type::from(self.ty)
+    }
+}
+
 /// Structure types defined by the specification.
 ///
 /// Values between 0 and 127 are reserved and defined by the specification,
 /// all values above are vendor-specific.
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
-#[repr(u8)]
+///
+/// New variants are added as the specification defines new types, so code matching
+/// on a specific reserved value should compare the raw byte instead.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[non_exhaustive]
 pub enum Type {
     /// BIOS information.
     BiosInformation,
     /// System information.
     SystemInformation,
+    /// Baseboard or module information.
+    BaseboardInformation,
     /// System enclosure information.
-    SystemEnclosure = 3,
+    SystemEnclosure,
     /// Information about a processor.
-    ProcessorInformation = 4,
+    ProcessorInformation,
+    /// Information about the memory controller (obsolete).
+    MemoryControllerInformation,
+    /// Information about a memory module (obsolete).
+    MemoryModuleInformation,
     /// Information about processor caches.
-    CacheInformation = 7,
+    CacheInformation,
+    /// Information about a port connector.
+    PortConnectorInformation,
     /// Description of an upgradeable system slot.
-    SystemSlot = 9,
+    SystemSlot,
+    /// Information about on board devices (obsolete).
+    OnBoardDevicesInformation,
+    /// Free-form strings defined by the OEM.
+    OemStrings,
+    /// Information for configuring jumpers and switches.
+    SystemConfigurationOptions,
+    /// Languages supported by the BIOS.
+    BiosLanguageInformation,
+    /// Grouping of related structures.
+    GroupAssociations,
+    /// Information about the event log.
+    SystemEventLog,
     /// Information about an array of physical memory.
-    PhysicalMemoryArray = 16,
+    PhysicalMemoryArray,
     /// Information about a memory device.
-    MemoryDevice = 17,
+    MemoryDevice,
+    /// Information about a memory error, with 32-bit addresses.
+    MemoryError32,
     /// Information about what is a physical memory array mapped to.
-    MemoryArrayMappedAddress = 19,
+    MemoryArrayMappedAddress,
+    /// Information about what is a memory device mapped to.
+    MemoryDeviceMappedAddress,
+    /// Information about a built-in pointing device.
+    BuiltInPointingDevice,
+    /// Information about a portable battery.
+    PortableBattery,
+    /// Information about the system reset capabilities.
+    SystemReset,
+    /// Hardware security settings.
+    HardwareSecurity,
+    /// Information about timed power-on.
+    SystemPowerControls,
+    /// Information about a voltage probe.
+    VoltageProbe,
+    /// Information about a cooling device.
+    CoolingDevice,
+    /// Information about a temperature probe.
+    TemperatureProbe,
+    /// Information about an electrical current probe.
+    ElectricalCurrentProbe,
+    /// Information about out-of-band remote access.
+    OutOfBandRemoteAccess,
+    /// Entry point of the Boot Integrity Services.
+    BisEntryPoint,
     /// Information about the boot process.
-    SystemBootInformation = 32,
+    SystemBootInformation,
+    /// Information about a memory error, with 64-bit addresses.
+    MemoryError64,
+    /// Information about a system management device.
+    ManagementDevice,
+    /// A component of a management device.
+    ManagementDeviceComponent,
+    /// Thresholds of a management device component.
+    ManagementDeviceThresholdData,
+    /// Information about a memory channel.
+    MemoryChannel,
+    /// Information about the IPMI baseboard management controller.
+    IpmiDeviceInformation,
+    /// Information about a power supply.
+    SystemPowerSupply,
+    /// Additional information about other structures.
+    AdditionalInformation,
+    /// Information about an on board device.
+    OnboardDevicesExtendedInformation,
+    /// Interface to a management controller.
+    ManagementControllerHostInterface,
+    /// Information about a Trusted Platform Module.
+    TpmDevice,
+    /// Architecture-specific processor information.
+    ProcessorAdditionalInformation,
+    /// Information about a firmware component.
+    FirmwareInventoryInformation,
+    /// A string property of another structure.
+    StringProperty,
+    /// A structure which has been disabled.
+    Inactive,
+    /// Marks the end of the structure table.
+    EndOfTable,
+    /// A type reserved by the specification, which is not yet defined.
+    ///
+    /// Once a type is defined, it is reported by its own variant instead.
+    Reserved(u8),
+    /// A vendor-specific type, between 128 and 255.
+    Oem(u8),
+}
+
+impl From<u8> for Type {
+    fn from(ty: u8) -> Self {
+        match ty {
+            0 => Type::BiosInformation,
+            1 => Type::SystemInformation,
+            2 => Type::BaseboardInformation,
+            3 => Type::SystemEnclosure,
+            4 => Type::ProcessorInformation,
+            5 => Type::MemoryControllerInformation,
+            6 => Type::MemoryModuleInformation,
+            7 => Type::CacheInformation,
+            8 => Type::PortConnectorInformation,
+            9 => Type::SystemSlot,
+            10 => Type::OnBoardDevicesInformation,
+            11 => Type::OemStrings,
+            12 => Type::SystemConfigurationOptions,
+            13 => Type::BiosLanguageInformation,
+            14 => Type::GroupAssociations,
+            15 => Type::SystemEventLog,
+            16 => Type::PhysicalMemoryArray,
+            17 => Type::MemoryDevice,
+            18 => Type::MemoryError32,
+            19 => Type::MemoryArrayMappedAddress,
+            20 => Type::MemoryDeviceMappedAddress,
+            21 => Type::BuiltInPointingDevice,
+            22 => Type::PortableBattery,
+            23 => Type::SystemReset,
+            24 => Type::HardwareSecurity,
+            25 => Type::SystemPowerControls,
+            26 => Type::VoltageProbe,
+            27 => Type::CoolingDevice,
+            28 => Type::TemperatureProbe,
+            29 => Type::ElectricalCurrentProbe,
+            30 => Type::OutOfBandRemoteAccess,
+            31 => Type::BisEntryPoint,
+            32 => Type::SystemBootInformation,
+            33 => Type::MemoryError64,
+            34 => Type::ManagementDevice,
+            35 => Type::ManagementDeviceComponent,
+            36 => Type::ManagementDeviceThresholdData,
+            37 => Type::MemoryChannel,
+            38 => Type::IpmiDeviceInformation,
+            39 => Type::SystemPowerSupply,
+            40 => Type::AdditionalInformation,
+            41 => Type::OnboardDevicesExtendedInformation,
+            42 => Type::ManagementControllerHostInterface,
+            43 => Type::TpmDevice,
+            44 => Type::ProcessorAdditionalInformation,
+            45 => Type::FirmwareInventoryInformation,
+            46 => Type::StringProperty,
+            126 => Type::Inactive,
+            127 => Type::EndOfTable,
+            128..=255 => Type::Oem(ty),
+            _ => Type::Reserved(ty),
+        }
+    }
+}
+
+impl From<Type> for u8 {
+    fn from(ty: Type) -> Self {
+        match ty {
+            Type::BiosInformation => 0,
+            Type::SystemInformation => 1,
+            Type::BaseboardInformation => 2,
+            Type::SystemEnclosure => 3,
+            Type::ProcessorInformation => 4,
+            Type::MemoryControllerInformation => 5,
+            Type::MemoryModuleInformation => 6,
+            Type::CacheInformation => 7,
+            Type::PortConnectorInformation => 8,
+            Type::SystemSlot => 9,
+            Type::OnBoardDevicesInformation => 10,
+            Type::OemStrings => 11,
+            Type::SystemConfigurationOptions => 12,
+            Type::BiosLanguageInformation => 13,
+            Type::GroupAssociations => 14,
+            Type::SystemEventLog => 15,
+            Type::PhysicalMemoryArray => 16,
+            Type::MemoryDevice => 17,
+            Type::MemoryError32 => 18,
+            Type::MemoryArrayMappedAddress => 19,
+            Type::MemoryDeviceMappedAddress => 20,
+            Type::BuiltInPointingDevice => 21,
+            Type::PortableBattery => 22,
+            Type::SystemReset => 23,
+            Type::HardwareSecurity => 24,
+            Type::SystemPowerControls => 25,
+            Type::VoltageProbe => 26,
+            Type::CoolingDevice => 27,
+            Type::TemperatureProbe => 28,
+            Type::ElectricalCurrentProbe => 29,
+            Type::OutOfBandRemoteAccess => 30,
+            Type::BisEntryPoint => 31,
+            Type::SystemBootInformation => 32,
+            Type::MemoryError64 => 33,
+            Type::ManagementDevice => 34,
+            Type::ManagementDeviceComponent => 35,
+            Type::ManagementDeviceThresholdData => 36,
+            Type::MemoryChannel => 37,
+            Type::IpmiDeviceInformation => 38,
+            Type::SystemPowerSupply => 39,
+            Type::AdditionalInformation => 40,
+            Type::OnboardDevicesExtendedInformation => 41,
+            Type::ManagementControllerHostInterface => 42,
+            Type::TpmDevice => 43,
+            Type::ProcessorAdditionalInformation => 44,
+            Type::FirmwareInventoryInformation => 45,
+            Type::StringProperty => 46,
+            Type::Inactive => 126,
+            Type::EndOfTable => 127,
+            Type::Reserved(ty) | Type::Oem(ty) => ty,
+        }
+    }
+}
+
+impl fmt::Display for Type {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match *self {
+            Type::BiosInformation => "BIOS Information",
+            Type::SystemInformation => "System Information",
+            Type::BaseboardInformation => "Baseboard (or Module) Information",
+            Type::SystemEnclosure => "System Enclosure or Chassis",
+            Type::ProcessorInformation => "Processor Information",
+            Type::MemoryControllerInformation => "Memory Controller Information",
+            Type::MemoryModuleInformation => "Memory Module Information",
+            Type::CacheInformation => "Cache Information",
+            Type::PortConnectorInformation => "Port Connector Information",
+            Type::SystemSlot => "System Slots",
+            Type::OnBoardDevicesInformation => "On Board Devices Information",
+            Type::OemStrings => "OEM Strings",
+            Type::SystemConfigurationOptions => "System Configuration Options",
+            Type::BiosLanguageInformation => "BIOS Language Information",
+            Type::GroupAssociations => "Group Associations",
+            Type::SystemEventLog => "System Event Log",
+            Type::PhysicalMemoryArray => "Physical Memory Array",
+            Type::MemoryDevice => "Memory Device",
+            Type::MemoryError32 => "32-Bit Memory Error Information",
+            Type::MemoryArrayMappedAddress => "Memory Array Mapped Address",
+            Type::MemoryDeviceMappedAddress => "Memory Device Mapped Address",
+            Type::BuiltInPointingDevice => "Built-in Pointing Device",
+            Type::PortableBattery => "Portable Battery",
+            Type::SystemReset => "System Reset",
+            Type::HardwareSecurity => "Hardware Security",
+            Type::SystemPowerControls => "System Power Controls",
+            Type::VoltageProbe => "Voltage Probe",
+            Type::CoolingDevice => "Cooling Device",
+            Type::TemperatureProbe => "Temperature Probe",
+            Type::ElectricalCurrentProbe => "Electrical Current Probe",
+            Type::OutOfBandRemoteAccess => "Out-of-Band Remote Access",
+            Type::BisEntryPoint => "Boot Integrity Services (BIS) Entry Point",
+            Type::SystemBootInformation => "System Boot Information",
+            Type::MemoryError64 => "64-Bit Memory Error Information",
+            Type::ManagementDevice => "Management Device",
+            Type::ManagementDeviceComponent => "Management Device Component",
+            Type::ManagementDeviceThresholdData => "Management Device Threshold Data",
+            Type::MemoryChannel => "Memory Channel",
+            Type::IpmiDeviceInformation => "IPMI Device Information",
+            Type::SystemPowerSupply => "System Power Supply",
+            Type::AdditionalInformation => "Additional Information",
+            Type::OnboardDevicesExtendedInformation => "Onboard Devices Extended Information",
+            Type::ManagementControllerHostInterface => "Management Controller Host Interface",
+            Type::TpmDevice => "TPM Device",
+            Type::ProcessorAdditionalInformation => "Processor Additional Information",
+            Type::FirmwareInventoryInformation => "Firmware Inventory Information",
+            Type::StringProperty => "String Property",
+            Type::Inactive => "Inactive",
+            Type::EndOfTable => "End-of-Table",
+            Type::Reserved(ty) => return write!(f, "Reserved ({})", ty),
+            Type::Oem(ty) => return write!(f, "OEM-specific ({})", ty),
+        };
+        f.write_str(name)
+    }
 }
 
 /// BIOS information structure.
@@ -146,6 +803,8 @@ pub enum Type {
 /// This structure has a variable size byte array at the end,
 /// you should go to the `length - 18 bytes` offset and also parse
 /// the `BiosInformationTail` structure.
+///
+/// `BiosInformationView` handles the variable layout without the need for unsafe code.
 #[derive(Debug, Copy, Clone)]
 #[repr(C, packed)]
 pub struct BiosInformation {
@@ -203,27 +862,345 @@ bitflags! {
     ///
     /// See the spec for more information.
     pub struct BiosCharacteristics: u64 {
+        /// Characteristics are unknown.
+        const UNKNOWN = 1 << 2;
         /// Set if BIOS characteristics are not supported.
         const NOT_SUPPORTED = 1 << 3;
+        /// ISA is supported.
+        const ISA = 1 << 4;
+        /// MCA is supported.
+        const MCA = 1 << 5;
+        /// EISA is supported.
+        const EISA = 1 << 6;
         /// PCI is supported.
         const PCI = 1 << 7;
+        /// PC Card (PCMCIA) is supported.
+        const PC_CARD = 1 << 8;
         /// Plug-and-Play BIOS.
         const PNP = 1 << 9;
+        /// APM is supported.
+        const APM = 1 << 10;
+        /// BIOS is upgradeable (Flash).
+        const UPGRADEABLE = 1 << 11;
+        /// BIOS shadowing is allowed.
+        const SHADOWING = 1 << 12;
+        /// VL-VESA is supported.
+        const VL_VESA = 1 << 13;
+        /// ESCD support is available.
+        const ESCD = 1 << 14;
+        /// Boot from CD is supported.
+        const BOOT_FROM_CD = 1 << 15;
+        /// Selectable boot is supported.
+        const SELECTABLE_BOOT = 1 << 16;
+        /// BIOS ROM is socketed.
+        const ROM_SOCKETED = 1 << 17;
+        /// Boot from PC Card (PCMCIA) is supported.
+        const BOOT_FROM_PC_CARD = 1 << 18;
+        /// EDD specification is supported.
+        const EDD = 1 << 19;
+        /// Int 13h, Japanese floppy for NEC 9800 1.2 MB (3.5", 1K bytes/sector, 360 RPM).
+        const FLOPPY_NEC_9800 = 1 << 20;
+        /// Int 13h, Japanese floppy for Toshiba 1.2 MB (3.5", 360 RPM).
+        const FLOPPY_TOSHIBA = 1 << 21;
+        /// Int 13h, 5.25" / 360 KB floppy services.
+        const FLOPPY_525_360K = 1 << 22;
+        /// Int 13h, 5.25" / 1.2 MB floppy services.
+        const FLOPPY_525_1200K = 1 << 23;
+        /// Int 13h, 3.5" / 720 KB floppy services.
+        const FLOPPY_35_720K = 1 << 24;
+        /// Int 13h, 3.5" / 2.88 MB floppy services.
+        const FLOPPY_35_2880K = 1 << 25;
+        /// Int 5h, print screen service.
+        const PRINT_SCREEN = 1 << 26;
+        /// Int 9h, 8042 keyboard services.
+        const KEYBOARD_8042 = 1 << 27;
+        /// Int 14h, serial services.
+        const SERIAL = 1 << 28;
+        /// Int 17h, printer services.
+        const PRINTER = 1 << 29;
+        /// Int 10h, CGA/Mono video services.
+        const CGA_MONO_VIDEO = 1 << 30;
+        /// NEC PC-98.
+        const NEC_PC_98 = 1 << 31;
+        /// Bits reserved for the BIOS vendor.
+        const BIOS_VENDOR_RESERVED = 0xFFFF << 32;
+        /// Bits reserved for the system vendor.
+        const SYSTEM_VENDOR_RESERVED = 0xFFFF << 48;
+    }
+}
+
+impl BiosCharacteristics {
+    /// The bits reserved for the BIOS vendor.
+    pub fn bios_vendor_bits(&self) -> u16 {
+        (self.bits() >> 32) as u16
+    }
+
+    /// The bits reserved for the system vendor.
+    pub fn system_vendor_bits(&self) -> u16 {
+        (self.bits() >> 48) as u16
     }
 }
 
 bitflags! {
     /// Extended BIOS characteristics.
+    ///
+    /// The low byte is the first extension byte, the high byte is the second one.
     pub struct BiosExtendedCharacteristics: u16 {
         /// ACPI support.
         const ACPI = 1 << 0;
         /// Legacy USB support (emulate USB keyboard as PS/2 keyboard).
         const USB_LEGACY = 1 << 1;
+        /// AGP support.
+        const AGP = 1 << 2;
+        /// Boot from I2O is supported.
+        const I2O_BOOT = 1 << 3;
+        /// Boot from LS-120 SuperDisk is supported.
+        const LS120_BOOT = 1 << 4;
+        /// Boot from ATAPI ZIP drive is supported.
+        const ATAPI_ZIP_BOOT = 1 << 5;
+        /// Boot from 1394 is supported.
+        const IEEE1394_BOOT = 1 << 6;
         /// Smart Battery support.
         const SMART_BATTERY = 1 << 7;
+        /// BIOS Boot Specification is supported.
+        const BIOS_BOOT_SPEC = 1 << 8;
+        /// Function key-initiated network service boot is supported.
+        const NETWORK_BOOT = 1 << 9;
+        /// Targeted content distribution is enabled.
+        const TARGETED_CONTENT_DISTRIBUTION = 1 << 10;
         /// UEFI firmware supported.
         const UEFI = 1 << 11;
         /// Running in a virtual machine.
         const VIRTUAL_MACHINE = 1 << 12;
+        /// Manufacturing mode is supported.
+        const MANUFACTURING_MODE_SUPPORTED = 1 << 13;
+        /// Manufacturing mode is enabled.
+        const MANUFACTURING_MODE_ENABLED = 1 << 14;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Builds a valid SMBIOS 2 entry point reporting `length`.
+    fn smbios2(length: u8) -> [u8; 0x1F] {
+        let mut eps = [0; 0x1F];
+        eps[0x00..0x04].copy_from_slice(b"_SM_");
+        eps[0x05] = length;
+        eps[0x06] = 2;
+        eps[0x07] = 8;
+        eps[0x08] = 0x80;
+        eps[0x10..0x15].copy_from_slice(b"_DMI_");
+        eps[0x16] = 0x34;
+        eps[0x17] = 0x12;
+        eps[0x18..0x1C].copy_from_slice(&[0x00, 0x00, 0x0F, 0x00]);
+        eps[0x1C] = 42;
+        eps[0x1E] = 0x28;
+        fix_smbios2_checksums(&mut eps);
+        eps
+    }
+
+    fn fix_smbios2_checksums(eps: &mut [u8]) {
+        eps[0x15] = 0;
+        eps[0x15] = checksum(&eps[0x10..0x1F]).wrapping_neg();
+        let length = eps[0x05] as usize;
+        eps[0x04] = 0;
+        eps[0x04] = checksum(&eps[..length]).wrapping_neg();
+    }
+
+    #[test]
+    fn smbios2_valid() {
+        let eps = Smbios2EntryPoint::parse(&smbios2(0x1F)).unwrap();
+        assert_eq!({ eps.smbios_version }, (2, 8));
+        assert_eq!({ eps.max_size }, 0x80);
+        assert_eq!({ eps.table_size }, 0x1234);
+        assert_eq!({ eps.table_addr }, 0xF0000);
+        assert_eq!({ eps.table_len }, 42);
+        assert_eq!(eps.bcd_revision, 0x28);
+    }
+
+    #[test]
+    fn smbios2_length_quirk() {
+        let eps = smbios2(0x1E);
+        // The checksum only covers the first 0x1E bytes, not the BCD revision.
+        assert_ne!(checksum(&eps), 0);
+        assert!(Smbios2EntryPoint::parse(&eps).is_ok());
+    }
+
+    #[test]
+    fn smbios2_errors() {
+        let eps = smbios2(0x1F);
+        assert_eq!(
+            Smbios2EntryPoint::parse(&eps[..0x1E]).unwrap_err(),
+            EntryPointError::Truncated
+        );
+
+        let mut long = [0; 0x20];
+        long[..0x1F].copy_from_slice(&eps);
+        long[0x05] = 0x20;
+        fix_smbios2_checksums(&mut long);
+        assert_eq!(
+            Smbios2EntryPoint::parse(&long[..0x1F]).unwrap_err(),
+            EntryPointError::Truncated
+        );
+
+        let mut bad = eps;
+        bad[0x00] = b'X';
+        assert_eq!(
+            Smbios2EntryPoint::parse(&bad).unwrap_err(),
+            EntryPointError::BadAnchor
+        );
+
+        let mut bad = eps;
+        bad[0x05] = 0x1D;
+        fix_smbios2_checksums(&mut bad);
+        assert_eq!(
+            Smbios2EntryPoint::parse(&bad).unwrap_err(),
+            EntryPointError::BadLength
+        );
+
+        let mut bad = eps;
+        bad[0x04] = bad[0x04].wrapping_add(1);
+        assert_eq!(
+            Smbios2EntryPoint::parse(&bad).unwrap_err(),
+            EntryPointError::BadChecksum
+        );
+
+        let mut bad = eps;
+        bad[0x10] = b'X';
+        fix_smbios2_checksums(&mut bad);
+        assert_eq!(
+            Smbios2EntryPoint::parse(&bad).unwrap_err(),
+            EntryPointError::BadIntermediateAnchor
+        );
+
+        // Keep the whole structure adding up to 0, but not the intermediate EPS.
+        let mut bad = eps;
+        bad[0x15] = bad[0x15].wrapping_add(1);
+        bad[0x0B] = bad[0x0B].wrapping_sub(1);
+        assert_eq!(
+            Smbios2EntryPoint::parse(&bad).unwrap_err(),
+            EntryPointError::BadIntermediateChecksum
+        );
+    }
+
+    /// Builds a valid SMBIOS 3 entry point.
+    fn smbios3() -> [u8; 0x18] {
+        let mut eps = [0; 0x18];
+        eps[0x00..0x05].copy_from_slice(b"_SM3_");
+        eps[0x06] = 0x18;
+        eps[0x07..0x0A].copy_from_slice(&[3, 4, 1]);
+        eps[0x0A] = 1;
+        eps[0x0C..0x10].copy_from_slice(&[0x78, 0x56, 0x34, 0x12]);
+        eps[0x10..0x18].copy_from_slice(&[0x00, 0x10, 0x32, 0x54, 0x76, 0x98, 0x00, 0x00]);
+        fix_smbios3_checksum(&mut eps);
+        eps
+    }
+
+    fn fix_smbios3_checksum(eps: &mut [u8]) {
+        let length = eps[0x06] as usize;
+        eps[0x05] = 0;
+        eps[0x05] = checksum(&eps[..length]).wrapping_neg();
+    }
+
+    #[test]
+    fn smbios3_valid() {
+        let eps = Smbios3EntryPoint::parse(&smbios3()).unwrap();
+        assert_eq!({ eps.version }, (3, 4, 1));
+        assert_eq!({ eps.max_size }, 0x1234_5678);
+        assert_eq!({ eps.address }, 0x9876_5432_1000);
+    }
+
+    #[test]
+    fn smbios3_errors() {
+        let eps = smbios3();
+
+        let mut bad = eps;
+        bad[0x06] = 0x17;
+        fix_smbios3_checksum(&mut bad);
+        assert_eq!(
+            Smbios3EntryPoint::parse(&bad).unwrap_err(),
+            EntryPointError::BadLength
+        );
+
+        let mut bad = eps;
+        bad[0x06] = 0x19;
+        bad[0x05] = bad[0x05].wrapping_sub(1);
+        assert_eq!(
+            Smbios3EntryPoint::parse(&bad).unwrap_err(),
+            EntryPointError::Truncated
+        );
+
+        let mut bad = eps;
+        bad[0x05] = bad[0x05].wrapping_add(1);
+        assert_eq!(
+            Smbios3EntryPoint::parse(&bad).unwrap_err(),
+            EntryPointError::BadChecksum
+        );
+
+        let mut bad = eps;
+        bad[0x0A] = 2;
+        fix_smbios3_checksum(&mut bad);
+        assert_eq!(
+            Smbios3EntryPoint::parse(&bad).unwrap_err(),
+            EntryPointError::BadRevision
+        );
+    }
+
+    #[test]
+    fn find_entry_points_in_window() {
+        let mut memory = [0; 0x10000];
+        memory[0x100..0x11F].copy_from_slice(&smbios2(0x1F));
+        memory[0x200..0x218].copy_from_slice(&smbios3());
+
+        // Bad checksum.
+        memory[0x300..0x31F].copy_from_slice(&smbios2(0x1F));
+        memory[0x304] = memory[0x304].wrapping_add(1);
+
+        // Not on a 16-byte boundary.
+        memory[0x408..0x420].copy_from_slice(&smbios3());
+
+        // Cut off by the end of the window.
+        memory[0xFFF0..0x10000].copy_from_slice(&smbios3()[..0x10]);
+
+        let mut found = find_entry_points(&memory);
+        match found.next() {
+            Some((0x100, EntryPoint::Smbios2(_))) => (),
+            other => panic!("unexpected entry point {:?}", other),
+        }
+        match found.next() {
+            Some((0x200, EntryPoint::Smbios3(_))) => (),
+            other => panic!("unexpected entry point {:?}", other),
+        }
+        assert!(found.next().is_none());
+    }
+
+    #[test]
+    fn entry_point_by_anchor() {
+        let eps = EntryPoint::parse(&smbios2(0x1F)).unwrap();
+        match eps {
+            EntryPoint::Smbios2(_) => (),
+            EntryPoint::Smbios3(_) => panic!("expected a SMBIOS 2 entry point"),
+        }
+        assert_eq!(eps.version(), SmbiosVersion::new(2, 8, 0));
+        assert_eq!(eps.structure_count(), Some(42));
+        assert_eq!(eps.table_address(), 0xF0000);
+        assert_eq!(eps.table_max_size(), 0x1234);
+
+        let eps = EntryPoint::parse(&smbios3()).unwrap();
+        match eps {
+            EntryPoint::Smbios3(_) => (),
+            EntryPoint::Smbios2(_) => panic!("expected a SMBIOS 3 entry point"),
+        }
+        assert_eq!(eps.version(), SmbiosVersion::new(3, 4, 1));
+        assert_eq!(eps.structure_count(), None);
+        assert_eq!(eps.table_address(), 0x9876_5432_1000);
+        assert_eq!(eps.table_max_size(), 0x1234_5678);
+
+        assert_eq!(
+            EntryPoint::parse(b"_DMI_").unwrap_err(),
+            EntryPointError::BadAnchor
+        );
     }
 }
@@ -0,0 +1,55 @@
+//! Helper macros used to define structure fields.
+
+/// Defines an enum for a field whose values are listed in the spec.
+///
+/// Each variant is documented and displayed using the text from the spec.
+/// Values which are not listed are kept in an `Undefined` variant.
+///
+/// The fallback is not named `Unknown`, since many fields define an "Unknown" value
+/// of their own (usually 0x02), which gets a regular variant.
+macro_rules! spec_enum {
+    (
+        $(#[$attr:meta])*
+        pub enum $name:ident: $repr:ty {
+            $($variant:ident = $value:literal => $text:expr,)*
+        }
+    ) => {
+        $(#[$attr])*
+        #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+        pub enum $name {
+            $(
+                #[doc = $text]
+                $variant,
+            )*
+            /// A value which is not defined by the spec.
+            Undefined($repr),
+        }
+
+        impl From<$repr> for $name {
+            fn from(value: $repr) -> Self {
+                match value {
+                    $($value => $name::$variant,)*
+                    _ => $name::Undefined(value),
+                }
+            }
+        }
+
+        impl From<$name> for $repr {
+            fn from(value: $name) -> Self {
+                match value {
+                    $($name::$variant => $value,)*
+                    $name::Undefined(value) => value,
+                }
+            }
+        }
+
+        impl ::core::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                match *self {
+                    $($name::$variant => f.write_str($text),)*
+                    $name::Undefined(value) => write!(f, "Undefined ({:#x})", value),
+                }
+            }
+        }
+    };
+}
@@ -0,0 +1,221 @@
+//! Memory controller information (type 5).
+//!
+//! This structure is obsolete since SMBIOS 2.1, replaced by the physical memory array.
+
+use processor::LegacyVoltages;
+use table::{Handles, RawStructure};
+use Type;
+
+/// Safe view of a memory controller information structure.
+#[derive(Debug, Copy, Clone)]
+pub struct MemoryControllerInformationView<'a> {
+    raw: RawStructure<'a>,
+}
+
+impl<'a> MemoryControllerInformationView<'a> {
+    /// Interprets a raw structure as memory controller information.
+    ///
+    /// Returns `None` if the structure has a different type or is too short.
+    pub fn new(raw: RawStructure<'a>) -> Option<Self> {
+        if raw.ty() != Type::MemoryControllerInformation || raw.formatted().len() < 0x0F {
+            return None;
+        }
+        Some(MemoryControllerInformationView { raw })
+    }
+
+    /// The underlying structure.
+    pub fn raw(&self) -> RawStructure<'a> {
+        self.raw
+    }
+
+    /// Error detecting method of the controller.
+    pub fn error_detecting_method(&self) -> ErrorDetectingMethod {
+        ErrorDetectingMethod::from(self.raw.byte(0x04).unwrap_or(0))
+    }
+
+    /// Error correcting capabilities of the controller.
+    pub fn error_correcting_capabilities(&self) -> ErrorCorrectingCapabilities {
+        ErrorCorrectingCapabilities::from_bits_truncate(self.raw.byte(0x05).unwrap_or(0))
+    }
+
+    /// Interleave supported by the controller.
+    pub fn supported_interleave(&self) -> Interleave {
+        Interleave::from(self.raw.byte(0x06).unwrap_or(0))
+    }
+
+    /// Interleave currently used by the controller.
+    pub fn current_interleave(&self) -> Interleave {
+        Interleave::from(self.raw.byte(0x07).unwrap_or(0))
+    }
+
+    /// Size of the largest memory module supported in a single slot, in bytes.
+    ///
+    /// Returns `None` if the size does not fit in 64 bits.
+    pub fn max_module_size(&self) -> Option<u64> {
+        let exponent = u32::from(self.raw.byte(0x08).unwrap_or(0));
+        1u64.checked_shl(exponent + 20)
+    }
+
+    /// Speeds supported by the controller.
+    pub fn supported_speeds(&self) -> MemorySpeeds {
+        MemorySpeeds::from_bits_truncate(self.raw.word(0x09).unwrap_or(0))
+    }
+
+    /// Memory types supported by the controller.
+    pub fn supported_memory_types(&self) -> MemoryTypes {
+        MemoryTypes::from_bits_truncate(self.raw.word(0x0B).unwrap_or(0))
+    }
+
+    /// Voltages supported by the memory modules.
+    pub fn module_voltages(&self) -> LegacyVoltages {
+        LegacyVoltages::from_bits_truncate(self.raw.byte(0x0D).unwrap_or(0))
+    }
+
+    /// Returns an iterator over the handles of the memory modules in the controller's slots.
+    pub fn memory_module_handles(&self) -> Handles<'a> {
+        let count = self.raw.byte(0x0E).unwrap_or(0) as usize;
+        self.raw.handles(0x0F, count)
+    }
+
+    /// Error correcting capabilities which are currently enabled.
+    ///
+    /// Only supported by SMBIOS 2.1+.
+    pub fn enabled_error_correcting_capabilities(&self) -> Option<ErrorCorrectingCapabilities> {
+        let count = self.raw.byte(0x0E)? as usize;
+        self.raw
+            .byte(0x0F + 2 * count)
+            .map(ErrorCorrectingCapabilities::from_bits_truncate)
+    }
+}
+
+spec_enum! {
+    /// Method used by a memory controller to detect errors.
+    pub enum ErrorDetectingMethod: u8 {
+        Other = 0x01 => "Other",
+        Unknown = 0x02 => "Unknown",
+        None = 0x03 => "None",
+        Parity8Bit = 0x04 => "8-bit Parity",
+        Ecc32Bit = 0x05 => "32-bit ECC",
+        Ecc64Bit = 0x06 => "64-bit ECC",
+        Ecc128Bit = 0x07 => "128-bit ECC",
+        Crc = 0x08 => "CRC",
+    }
+}
+
+bitflags! {
+    /// Error correcting capabilities of a memory controller.
+    pub struct ErrorCorrectingCapabilities: u8 {
+        /// Other capability.
+        const OTHER = 1 << 0;
+        /// Capabilities are unknown.
+        const UNKNOWN = 1 << 1;
+        /// Errors are not corrected.
+        const NONE = 1 << 2;
+        /// Single-bit errors are corrected.
+        const SINGLE_BIT = 1 << 3;
+        /// Double-bit errors are corrected.
+        const DOUBLE_BIT = 1 << 4;
+        /// Errors are scrubbed.
+        const SCRUBBING = 1 << 5;
+    }
+}
+
+spec_enum! {
+    /// Memory interleave of a controller.
+    pub enum Interleave: u8 {
+        Other = 0x01 => "Other",
+        Unknown = 0x02 => "Unknown",
+        OneWay = 0x03 => "One-Way Interleave",
+        TwoWay = 0x04 => "Two-Way Interleave",
+        FourWay = 0x05 => "Four-Way Interleave",
+        EightWay = 0x06 => "Eight-Way Interleave",
+        SixteenWay = 0x07 => "Sixteen-Way Interleave",
+    }
+}
+
+bitflags! {
+    /// Speeds of memory modules.
+    pub struct MemorySpeeds: u16 {
+        /// Other speed.
+        const OTHER = 1 << 0;
+        /// Speed is unknown.
+        const UNKNOWN = 1 << 1;
+        /// 70ns.
+        const NS70 = 1 << 2;
+        /// 60ns.
+        const NS60 = 1 << 3;
+        /// 50ns.
+        const NS50 = 1 << 4;
+    }
+}
+
+bitflags! {
+    /// Types of memory modules.
+    pub struct MemoryTypes: u16 {
+        /// Other type.
+        const OTHER = 1 << 0;
+        /// Type is unknown.
+        const UNKNOWN = 1 << 1;
+        /// Standard.
+        const STANDARD = 1 << 2;
+        /// Fast Page Mode.
+        const FAST_PAGE_MODE = 1 << 3;
+        /// EDO.
+        const EDO = 1 << 4;
+        /// Parity.
+        const PARITY = 1 << 5;
+        /// ECC.
+        const ECC = 1 << 6;
+        /// SIMM.
+        const SIMM = 1 << 7;
+        /// DIMM.
+        const DIMM = 1 << 8;
+        /// Burst EDO.
+        const BURST_EDO = 1 << 9;
+        /// SDRAM.
+        const SDRAM = 1 << 10;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use table::TestStructure;
+
+    #[test]
+    fn max_module_size() {
+        let data = TestStructure::new(5, 0x0F);
+        let controller = MemoryControllerInformationView::new(data.raw()).unwrap();
+        assert_eq!(controller.max_module_size(), Some(1 << 20));
+
+        let data = TestStructure::new(5, 0x0F).field(0x08, &[11]);
+        let controller = MemoryControllerInformationView::new(data.raw()).unwrap();
+        assert_eq!(controller.max_module_size(), Some(2 << 30));
+
+        // 2^44 MiB does not fit in 64 bits.
+        let data = TestStructure::new(5, 0x0F).field(0x08, &[44]);
+        let controller = MemoryControllerInformationView::new(data.raw()).unwrap();
+        assert_eq!(controller.max_module_size(), None);
+    }
+
+    #[test]
+    fn enabled_capabilities_after_handles() {
+        let data = TestStructure::new(5, 0x14)
+            .field(0x05, &[0x38])
+            .field(0x0E, &[2, 0x10, 0x00, 0x11, 0x00, 0x08]);
+        let controller = MemoryControllerInformationView::new(data.raw()).unwrap();
+        let mut handles = controller.memory_module_handles();
+        assert_eq!(handles.next(), Some(0x10));
+        assert_eq!(handles.next(), Some(0x11));
+        assert_eq!(handles.next(), None);
+        assert_eq!(
+            controller.enabled_error_correcting_capabilities(),
+            Some(ErrorCorrectingCapabilities::SINGLE_BIT)
+        );
+
+        // Before SMBIOS 2.1, the structure ends after the handles.
+        let data = TestStructure::new(5, 0x13).field(0x0E, &[2]);
+        let controller = MemoryControllerInformationView::new(data.raw()).unwrap();
+        assert_eq!(controller.enabled_error_correcting_capabilities(), None);
+    }
+}
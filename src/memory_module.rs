@@ -0,0 +1,159 @@
+//! Memory module information (type 6).
+//!
+//! This structure is obsolete since SMBIOS 2.1, replaced by the memory device.
+
+use memory_controller::MemoryTypes;
+use table::RawStructure;
+use Type;
+
+/// Safe view of a memory module information structure.
+#[derive(Debug, Copy, Clone)]
+pub struct MemoryModuleInformationView<'a> {
+    raw: RawStructure<'a>,
+}
+
+impl<'a> MemoryModuleInformationView<'a> {
+    /// Interprets a raw structure as memory module information.
+    ///
+    /// Returns `None` if the structure has a different type or is too short.
+    pub fn new(raw: RawStructure<'a>) -> Option<Self> {
+        if raw.ty() != Type::MemoryModuleInformation || raw.formatted().len() < 0x0C {
+            return None;
+        }
+        Some(MemoryModuleInformationView { raw })
+    }
+
+    /// The underlying structure.
+    pub fn raw(&self) -> RawStructure<'a> {
+        self.raw
+    }
+
+    /// Designation of the socket, as printed on the board.
+    pub fn socket_designation(&self) -> Option<&'a str> {
+        self.raw.string_lossy(0x04)
+    }
+
+    /// Banks (RAS# lines) the module is connected to.
+    ///
+    /// Each entry is `None` if there is no connection.
+    pub fn bank_connections(&self) -> [Option<u8>; 2] {
+        let connections = self.raw.byte(0x05).unwrap_or(0xFF);
+        let bank = |n| if n == 0xF { None } else { Some(n) };
+        [bank(connections >> 4), bank(connections & 0xF)]
+    }
+
+    /// Speed of the module, in nanoseconds.
+    ///
+    /// Returns `None` if the speed is unknown.
+    pub fn current_speed(&self) -> Option<u8> {
+        match self.raw.byte(0x06) {
+            Some(0) | None => None,
+            speed => speed,
+        }
+    }
+
+    /// Type of the module.
+    pub fn current_memory_type(&self) -> MemoryTypes {
+        MemoryTypes::from_bits_truncate(self.raw.word(0x07).unwrap_or(0))
+    }
+
+    /// Size of the installed memory.
+    pub fn installed_size(&self) -> ModuleSize {
+        ModuleSize(self.raw.byte(0x09).unwrap_or(0x7F))
+    }
+
+    /// Size of the enabled memory.
+    pub fn enabled_size(&self) -> ModuleSize {
+        ModuleSize(self.raw.byte(0x0A).unwrap_or(0x7F))
+    }
+
+    /// Errors reported for the module.
+    pub fn error_status(&self) -> ModuleErrorStatus {
+        ModuleErrorStatus::from_bits_truncate(self.raw.byte(0x0B).unwrap_or(0))
+    }
+}
+
+/// Size of a memory module.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct ModuleSize(pub u8);
+
+impl ModuleSize {
+    /// Size of the module, in bytes.
+    ///
+    /// Returns `None` if the size could not be determined,
+    /// or if the module is not installed or not enabled.
+    pub fn size(&self) -> Option<u64> {
+        match self.0 & 0x7F {
+            0x7D..=0x7F => None,
+            exponent => 1u64.checked_shl(u32::from(exponent) + 20),
+        }
+    }
+
+    /// Returns true if the size of the module could be determined.
+    pub fn is_determinable(&self) -> bool {
+        self.0 & 0x7F != 0x7D
+    }
+
+    /// Returns true if a module is installed.
+    pub fn is_installed(&self) -> bool {
+        self.0 & 0x7F != 0x7F
+    }
+
+    /// Returns true if the module is installed and enabled.
+    pub fn is_enabled(&self) -> bool {
+        self.is_installed() && self.0 & 0x7F != 0x7E
+    }
+
+    /// Returns true if the module has a double-bank connection.
+    pub fn is_double_bank(&self) -> bool {
+        self.0 & (1 << 7) != 0
+    }
+}
+
+bitflags! {
+    /// Errors reported for a memory module.
+    pub struct ModuleErrorStatus: u8 {
+        /// Uncorrectable errors were detected.
+        const UNCORRECTABLE = 1 << 0;
+        /// Correctable errors were detected.
+        const CORRECTABLE = 1 << 1;
+        /// The error status is stored in the event log.
+        const EVENT_LOG = 1 << 2;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn module_size() {
+        let size = ModuleSize(0x0A);
+        assert_eq!(size.size(), Some(1 << 30));
+        assert!(size.is_determinable() && size.is_installed() && size.is_enabled());
+        assert!(!size.is_double_bank());
+
+        let size = ModuleSize(0x80 | 0x0A);
+        assert_eq!(size.size(), Some(1 << 30));
+        assert!(size.is_double_bank());
+    }
+
+    #[test]
+    fn module_size_sentinels() {
+        let size = ModuleSize(0x7D);
+        assert_eq!(size.size(), None);
+        assert!(!size.is_determinable() && size.is_installed());
+
+        let size = ModuleSize(0x7E);
+        assert_eq!(size.size(), None);
+        assert!(size.is_determinable() && size.is_installed() && !size.is_enabled());
+
+        let size = ModuleSize(0x7F);
+        assert_eq!(size.size(), None);
+        assert!(!size.is_installed() && !size.is_enabled());
+
+        // The double-bank bit does not hide the sentinels.
+        assert_eq!(ModuleSize(0x80 | 0x7F).size(), None);
+        assert!(!ModuleSize(0x80 | 0x7F).is_installed());
+    }
+}
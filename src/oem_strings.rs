@@ -0,0 +1,108 @@
+//! OEM strings (type 11).
+
+use strings::StringSet;
+use table::RawStructure;
+use Type;
+
+/// Safe view of an OEM strings structure.
+#[derive(Debug, Copy, Clone)]
+pub struct OemStringsView<'a> {
+    raw: RawStructure<'a>,
+}
+
+impl<'a> OemStringsView<'a> {
+    /// Interprets a raw structure as OEM strings.
+    ///
+    /// Returns `None` if the structure has a different type or is too short.
+    pub fn new(raw: RawStructure<'a>) -> Option<Self> {
+        if raw.ty() != Type::OemStrings || raw.formatted().len() < 0x05 {
+            return None;
+        }
+        Some(OemStringsView { raw })
+    }
+
+    /// The underlying structure.
+    pub fn raw(&self) -> RawStructure<'a> {
+        self.raw
+    }
+
+    /// Number of strings.
+    pub fn count(&self) -> u8 {
+        self.raw.byte(0x04).unwrap_or(0)
+    }
+
+    /// The strings of this structure.
+    ///
+    /// Strings past the count of the structure are ignored.
+    pub fn strings(&self) -> StringSet<'a> {
+        self.raw.strings().take(self.count() as usize)
+    }
+
+    /// Finds the value of the first string of the form `key=value`.
+    ///
+    /// This is the convention used by hypervisors to pass metadata to a guest.
+    pub fn find_value(&self, key: &str) -> Option<&'a str> {
+        self.strings()
+            .iter_lossy()
+            .filter_map(|s| key_value(s, key))
+            .next()
+    }
+}
+
+/// Returns the value of a `key=value` string, if it has the right key.
+fn key_value<'a>(string: &'a str, key: &str) -> Option<&'a str> {
+    let mut parts = string.splitn(2, '=');
+    if parts.next()? != key {
+        return None;
+    }
+    parts.next()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use table::TestStructure;
+
+    #[test]
+    fn strings_limited_to_count() {
+        let data = TestStructure::new(11, 0x05)
+            .field(0x04, &[2])
+            .strings(&[b"first", b"second", b"extra"]);
+        let oem = OemStringsView::new(data.raw()).unwrap();
+        assert_eq!(oem.count(), 2);
+        assert_eq!(oem.strings().len(), 2);
+        assert_eq!(oem.strings().get(2), Some(&b"second"[..]));
+        assert_eq!(oem.strings().get(3), None);
+
+        let mut strings = oem.strings().iter_lossy();
+        assert_eq!(strings.next(), Some("first"));
+        assert_eq!(strings.next(), Some("second"));
+        assert_eq!(strings.next(), None);
+    }
+
+    #[test]
+    fn find_value() {
+        let data = TestStructure::new(11, 0x05).field(0x04, &[4]).strings(&[
+            b"foobar=1",
+            b"no separator",
+            b"empty=",
+            b"foo=a=b",
+        ]);
+        let oem = OemStringsView::new(data.raw()).unwrap();
+        assert_eq!(oem.find_value("foo"), Some("a=b"));
+        assert_eq!(oem.find_value("foobar"), Some("1"));
+        assert_eq!(oem.find_value("fooba"), None);
+        assert_eq!(oem.find_value("no separator"), None);
+        assert_eq!(oem.find_value("empty"), Some(""));
+    }
+
+    #[test]
+    fn find_value_within_count() {
+        let data = TestStructure::new(11, 0x05)
+            .field(0x04, &[1])
+            .strings(&[b"a=1", b"b=2"]);
+        let oem = OemStringsView::new(data.raw()).unwrap();
+        assert_eq!(oem.find_value("a"), Some("1"));
+        assert_eq!(oem.find_value("b"), None);
+    }
+}
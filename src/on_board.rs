@@ -0,0 +1,147 @@
+//! On board devices information (type 10).
+//!
+//! This structure is obsolete since SMBIOS 2.6, replaced by the on board devices extended information.
+
+use table::RawStructure;
+use Type;
+
+/// Safe view of an on board devices information structure.
+#[derive(Debug, Copy, Clone)]
+pub struct OnBoardDevicesInformationView<'a> {
+    raw: RawStructure<'a>,
+}
+
+impl<'a> OnBoardDevicesInformationView<'a> {
+    /// Interprets a raw structure as on board devices information.
+    ///
+    /// Returns `None` if the structure has a different type.
+    pub fn new(raw: RawStructure<'a>) -> Option<Self> {
+        if raw.ty() != Type::OnBoardDevicesInformation {
+            return None;
+        }
+        Some(OnBoardDevicesInformationView { raw })
+    }
+
+    /// The underlying structure.
+    pub fn raw(&self) -> RawStructure<'a> {
+        self.raw
+    }
+
+    /// Number of devices described by this structure.
+    pub fn count(&self) -> usize {
+        (self.raw.formatted().len() - 4) / 2
+    }
+
+    /// Returns an iterator over the devices.
+    pub fn devices(&self) -> OnBoardDevices<'a> {
+        OnBoardDevices {
+            raw: self.raw,
+            index: 0,
+            count: self.count(),
+        }
+    }
+}
+
+/// Iterator over the devices of an on board devices information structure.
+#[derive(Debug, Clone)]
+pub struct OnBoardDevices<'a> {
+    raw: RawStructure<'a>,
+    index: usize,
+    count: usize,
+}
+
+impl<'a> Iterator for OnBoardDevices<'a> {
+    type Item = OnBoardDevice<'a>;
+
+    fn next(&mut self) -> Option<OnBoardDevice<'a>> {
+        if self.index == self.count {
+            return None;
+        }
+
+        let offset = 0x04 + 2 * self.index;
+        let ty = self.raw.byte(offset)?;
+        self.index += 1;
+
+        Some(OnBoardDevice {
+            device_type: OnBoardDeviceType::from(ty & 0x7F),
+            enabled: ty & (1 << 7) != 0,
+            description: self.raw.string_lossy(offset + 1),
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.count - self.index;
+        (len, Some(len))
+    }
+}
+
+impl<'a> ExactSizeIterator for OnBoardDevices<'a> {}
+
+/// A device integrated on the motherboard.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct OnBoardDevice<'a> {
+    /// Type of the device.
+    pub device_type: OnBoardDeviceType,
+    /// Whether the device is enabled.
+    pub enabled: bool,
+    /// Description of the device.
+    pub description: Option<&'a str>,
+}
+
+spec_enum! {
+    /// Type of an on board device.
+    pub enum OnBoardDeviceType: u8 {
+        Other = 0x01 => "Other",
+        Unknown = 0x02 => "Unknown",
+        Video = 0x03 => "Video",
+        ScsiController = 0x04 => "SCSI Controller",
+        Ethernet = 0x05 => "Ethernet",
+        TokenRing = 0x06 => "Token Ring",
+        Sound = 0x07 => "Sound",
+        PataController = 0x08 => "PATA Controller",
+        SataController = 0x09 => "SATA Controller",
+        SasController = 0x0A => "SAS Controller",
+        WirelessLan = 0x0B => "Wireless LAN",
+        Bluetooth = 0x0C => "Bluetooth",
+        Wwan = 0x0D => "WWAN",
+        Emmc = 0x0E => "eMMC (embedded Multi-Media Controller)",
+        NvmeController = 0x0F => "NVMe Controller",
+        UfsController = 0x10 => "UFS Controller",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use table::TestStructure;
+
+    #[test]
+    fn devices() {
+        // The trailing byte does not make up a whole device.
+        let data = TestStructure::new(10, 0x09)
+            .field(0x04, &[0x83, 1, 0x05, 2, 0x7F])
+            .strings(&[b"Onboard VGA", b"Onboard LAN"]);
+        let view = OnBoardDevicesInformationView::new(data.raw()).unwrap();
+        assert_eq!(view.count(), 2);
+
+        let mut devices = view.devices();
+        assert_eq!(devices.len(), 2);
+        assert_eq!(
+            devices.next(),
+            Some(OnBoardDevice {
+                device_type: OnBoardDeviceType::Video,
+                enabled: true,
+                description: Some("Onboard VGA"),
+            })
+        );
+        assert_eq!(
+            devices.next(),
+            Some(OnBoardDevice {
+                device_type: OnBoardDeviceType::Ethernet,
+                enabled: false,
+                description: Some("Onboard LAN"),
+            })
+        );
+        assert_eq!(devices.next(), None);
+    }
+}
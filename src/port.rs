@@ -0,0 +1,145 @@
+//! Port connector information (type 8).
+
+use table::RawStructure;
+use Type;
+
+/// Safe view of a port connector information structure.
+#[derive(Debug, Copy, Clone)]
+pub struct PortConnectorInformationView<'a> {
+    raw: RawStructure<'a>,
+}
+
+impl<'a> PortConnectorInformationView<'a> {
+    /// Interprets a raw structure as port connector information.
+    ///
+    /// Returns `None` if the structure has a different type or is too short.
+    pub fn new(raw: RawStructure<'a>) -> Option<Self> {
+        if raw.ty() != Type::PortConnectorInformation || raw.formatted().len() < 0x09 {
+            return None;
+        }
+        Some(PortConnectorInformationView { raw })
+    }
+
+    /// The underlying structure.
+    pub fn raw(&self) -> RawStructure<'a> {
+        self.raw
+    }
+
+    /// Internal reference designator, as printed on the board.
+    pub fn internal_reference_designator(&self) -> Option<&'a str> {
+        self.raw.string_lossy(0x04)
+    }
+
+    /// Type of the internal connector.
+    pub fn internal_connector_type(&self) -> ConnectorType {
+        ConnectorType::from(self.raw.byte(0x05).unwrap_or(0))
+    }
+
+    /// External reference designation, such as the label next to the connector.
+    pub fn external_reference_designator(&self) -> Option<&'a str> {
+        self.raw.string_lossy(0x06)
+    }
+
+    /// Type of the external connector.
+    pub fn external_connector_type(&self) -> ConnectorType {
+        ConnectorType::from(self.raw.byte(0x07).unwrap_or(0))
+    }
+
+    /// Function of the port.
+    pub fn port_type(&self) -> PortType {
+        PortType::from(self.raw.byte(0x08).unwrap_or(0))
+    }
+}
+
+spec_enum! {
+    /// Type of a connector.
+    pub enum ConnectorType: u8 {
+        None = 0x00 => "None",
+        Centronics = 0x01 => "Centronics",
+        MiniCentronics = 0x02 => "Mini Centronics",
+        Proprietary = 0x03 => "Proprietary",
+        Db25PinMale = 0x04 => "DB-25 pin male",
+        Db25PinFemale = 0x05 => "DB-25 pin female",
+        Db15PinMale = 0x06 => "DB-15 pin male",
+        Db15PinFemale = 0x07 => "DB-15 pin female",
+        Db9PinMale = 0x08 => "DB-9 pin male",
+        Db9PinFemale = 0x09 => "DB-9 pin female",
+        Rj11 = 0x0A => "RJ-11",
+        Rj45 = 0x0B => "RJ-45",
+        MiniScsi50Pin = 0x0C => "50-pin MiniSCSI",
+        MiniDin = 0x0D => "Mini-DIN",
+        MicroDin = 0x0E => "Micro-DIN",
+        Ps2 = 0x0F => "PS/2",
+        Infrared = 0x10 => "Infrared",
+        HpHil = 0x11 => "HP-HIL",
+        AccessBusUsb = 0x12 => "Access Bus (USB)",
+        SsaScsi = 0x13 => "SSA SCSI",
+        CircularDin8Male = 0x14 => "Circular DIN-8 male",
+        CircularDin8Female = 0x15 => "Circular DIN-8 female",
+        OnBoardIde = 0x16 => "On Board IDE",
+        OnBoardFloppy = 0x17 => "On Board Floppy",
+        DualInline9Pin = 0x18 => "9-pin Dual Inline (pin 10 cut)",
+        DualInline25Pin = 0x19 => "25-pin Dual Inline (pin 26 cut)",
+        DualInline50Pin = 0x1A => "50-pin Dual Inline",
+        DualInline68Pin = 0x1B => "68-pin Dual Inline",
+        OnBoardSoundInputFromCdRom = 0x1C => "On Board Sound Input from CD-ROM",
+        MiniCentronicsType14 = 0x1D => "Mini-Centronics Type-14",
+        MiniCentronicsType26 = 0x1E => "Mini-Centronics Type-26",
+        MiniJack = 0x1F => "Mini-jack (headphones)",
+        Bnc = 0x20 => "BNC",
+        Ieee1394 = 0x21 => "1394",
+        SasSataPlugReceptacle = 0x22 => "SAS/SATA Plug Receptacle",
+        UsbTypeCReceptacle = 0x23 => "USB Type-C Receptacle",
+        Pc98 = 0xA0 => "PC-98",
+        Pc98Hireso = 0xA1 => "PC-98Hireso",
+        PcH98 = 0xA2 => "PC-H98",
+        Pc98Note = 0xA3 => "PC-98Note",
+        Pc98Full = 0xA4 => "PC-98Full",
+        Other = 0xFF => "Other",
+    }
+}
+
+spec_enum! {
+    /// Function of a port.
+    pub enum PortType: u8 {
+        None = 0x00 => "None",
+        ParallelXtAt = 0x01 => "Parallel Port XT/AT Compatible",
+        ParallelPs2 = 0x02 => "Parallel Port PS/2",
+        ParallelEcp = 0x03 => "Parallel Port ECP",
+        ParallelEpp = 0x04 => "Parallel Port EPP",
+        ParallelEcpEpp = 0x05 => "Parallel Port ECP/EPP",
+        SerialXtAt = 0x06 => "Serial Port XT/AT Compatible",
+        Serial16450 = 0x07 => "Serial Port 16450 Compatible",
+        Serial16550 = 0x08 => "Serial Port 16550 Compatible",
+        Serial16550A = 0x09 => "Serial Port 16550A Compatible",
+        Scsi = 0x0A => "SCSI Port",
+        Midi = 0x0B => "MIDI Port",
+        JoyStick = 0x0C => "Joy Stick Port",
+        Keyboard = 0x0D => "Keyboard Port",
+        Mouse = 0x0E => "Mouse Port",
+        SsaScsi = 0x0F => "SSA SCSI",
+        Usb = 0x10 => "USB",
+        FireWire = 0x11 => "FireWire (IEEE P1394)",
+        PcmciaTypeI = 0x12 => "PCMCIA Type I",
+        PcmciaTypeII = 0x13 => "PCMCIA Type II",
+        PcmciaTypeIII = 0x14 => "PCMCIA Type III",
+        Cardbus = 0x15 => "Cardbus",
+        AccessBus = 0x16 => "Access Bus Port",
+        ScsiII = 0x17 => "SCSI II",
+        ScsiWide = 0x18 => "SCSI Wide",
+        Pc98 = 0x19 => "PC-98",
+        Pc98Hireso = 0x1A => "PC-98-Hireso",
+        PcH98 = 0x1B => "PC-H98",
+        Video = 0x1C => "Video Port",
+        Audio = 0x1D => "Audio Port",
+        Modem = 0x1E => "Modem Port",
+        Network = 0x1F => "Network Port",
+        Sata = 0x20 => "SATA",
+        Sas = 0x21 => "SAS",
+        Mfdp = 0x22 => "MFDP (Multi-Function Display Port)",
+        Thunderbolt = 0x23 => "Thunderbolt",
+        Uart8251 = 0xA0 => "8251 Compatible",
+        Uart8251Fifo = 0xA1 => "8251 FIFO Compatible",
+        Other = 0xFF => "Other",
+    }
+}
@@ -0,0 +1,301 @@
+//! Processor families.
+
+spec_enum! {
+    /// Family of a processor.
+    pub enum ProcessorFamily: u16 {
+        Other = 0x01 => "Other",
+        Unknown = 0x02 => "Unknown",
+        I8086 = 0x03 => "8086",
+        I80286 = 0x04 => "80286",
+        Intel386 = 0x05 => "Intel386 processor",
+        Intel486 = 0x06 => "Intel486 processor",
+        I8087 = 0x07 => "8087",
+        I80287 = 0x08 => "80287",
+        I80387 = 0x09 => "80387",
+        I80487 = 0x0A => "80487",
+        Pentium = 0x0B => "Intel Pentium processor",
+        PentiumPro = 0x0C => "Pentium Pro processor",
+        PentiumII = 0x0D => "Pentium II processor",
+        PentiumMmx = 0x0E => "Pentium processor with MMX technology",
+        Celeron = 0x0F => "Intel Celeron processor",
+        PentiumIIXeon = 0x10 => "Pentium II Xeon processor",
+        PentiumIII = 0x11 => "Pentium III processor",
+        M1 = 0x12 => "M1 Family",
+        M2 = 0x13 => "M2 Family",
+        CeleronM = 0x14 => "Intel Celeron M processor",
+        Pentium4Ht = 0x15 => "Intel Pentium 4 HT processor",
+        IntelProcessor = 0x16 => "Intel Processor",
+        Duron = 0x18 => "AMD Duron Processor Family",
+        K5 = 0x19 => "K5 Family",
+        K6 = 0x1A => "K6 Family",
+        K6_2 = 0x1B => "K6-2",
+        K6_3 = 0x1C => "K6-3",
+        Athlon = 0x1D => "AMD Athlon Processor Family",
+        Amd29000 = 0x1E => "AMD29000 Family",
+        K6_2Plus = 0x1F => "K6-2+",
+        PowerPc = 0x20 => "Power PC Family",
+        PowerPc601 = 0x21 => "Power PC 601",
+        PowerPc603 = 0x22 => "Power PC 603",
+        PowerPc603Plus = 0x23 => "Power PC 603+",
+        PowerPc604 = 0x24 => "Power PC 604",
+        PowerPc620 = 0x25 => "Power PC 620",
+        PowerPcX704 = 0x26 => "Power PC x704",
+        PowerPc750 = 0x27 => "Power PC 750",
+        CoreDuo = 0x28 => "Intel Core Duo processor",
+        CoreDuoMobile = 0x29 => "Intel Core Duo mobile processor",
+        CoreSoloMobile = 0x2A => "Intel Core Solo mobile processor",
+        Atom = 0x2B => "Intel Atom processor",
+        CoreM = 0x2C => "Intel Core M processor",
+        CoreM3 = 0x2D => "Intel Core m3 processor",
+        CoreM5 = 0x2E => "Intel Core m5 processor",
+        CoreM7 = 0x2F => "Intel Core m7 processor",
+        Alpha = 0x30 => "Alpha Family",
+        Alpha21064 = 0x31 => "Alpha 21064",
+        Alpha21066 = 0x32 => "Alpha 21066",
+        Alpha21164 = 0x33 => "Alpha 21164",
+        Alpha21164Pc = 0x34 => "Alpha 21164PC",
+        Alpha21164a = 0x35 => "Alpha 21164a",
+        Alpha21264 = 0x36 => "Alpha 21264",
+        Alpha21364 = 0x37 => "Alpha 21364",
+        TurionIIUltraDualCoreMobileM = 0x38 => "AMD Turion II Ultra Dual-Core Mobile M Processor Family",
+        TurionIIDualCoreMobileM = 0x39 => "AMD Turion II Dual-Core Mobile M Processor Family",
+        AthlonIIDualCoreM = 0x3A => "AMD Athlon II Dual-Core M Processor Family",
+        Opteron6100 = 0x3B => "AMD Opteron 6100 Series Processor",
+        Opteron4100 = 0x3C => "AMD Opteron 4100 Series Processor",
+        Opteron6200 = 0x3D => "AMD Opteron 6200 Series Processor",
+        Opteron4200 = 0x3E => "AMD Opteron 4200 Series Processor",
+        AmdFx = 0x3F => "AMD FX Series Processor",
+        Mips = 0x40 => "MIPS Family",
+        MipsR4000 = 0x41 => "MIPS R4000",
+        MipsR4200 = 0x42 => "MIPS R4200",
+        MipsR4400 = 0x43 => "MIPS R4400",
+        MipsR4600 = 0x44 => "MIPS R4600",
+        MipsR10000 = 0x45 => "MIPS R10000",
+        AmdCSeries = 0x46 => "AMD C-Series Processor",
+        AmdESeries = 0x47 => "AMD E-Series Processor",
+        AmdASeries = 0x48 => "AMD A-Series Processor",
+        AmdGSeries = 0x49 => "AMD G-Series Processor",
+        AmdZSeries = 0x4A => "AMD Z-Series Processor",
+        AmdRSeries = 0x4B => "AMD R-Series Processor",
+        Opteron4300 = 0x4C => "AMD Opteron 4300 Series Processor",
+        Opteron6300 = 0x4D => "AMD Opteron 6300 Series Processor",
+        Opteron3300 = 0x4E => "AMD Opteron 3300 Series Processor",
+        FirePro = 0x4F => "AMD FirePro Series Processor",
+        Sparc = 0x50 => "SPARC Family",
+        SuperSparc = 0x51 => "SuperSPARC",
+        MicroSparcII = 0x52 => "microSPARC II",
+        MicroSparcIIep = 0x53 => "microSPARC IIep",
+        UltraSparc = 0x54 => "UltraSPARC",
+        UltraSparcII = 0x55 => "UltraSPARC II",
+        UltraSparcIii = 0x56 => "UltraSPARC Iii",
+        UltraSparcIII = 0x57 => "UltraSPARC III",
+        UltraSparcIIIi = 0x58 => "UltraSPARC IIIi",
+        M68040 = 0x60 => "68040 Family",
+        M68xxx = 0x61 => "68xxx",
+        M68000 = 0x62 => "68000",
+        M68010 = 0x63 => "68010",
+        M68020 = 0x64 => "68020",
+        M68030 = 0x65 => "68030",
+        AthlonX4QuadCore = 0x66 => "AMD Athlon X4 Quad-Core Processor Family",
+        OpteronX1000 = 0x67 => "AMD Opteron X1000 Series Processor",
+        OpteronX2000 = 0x68 => "AMD Opteron X2000 Series APU",
+        OpteronASeries = 0x69 => "AMD Opteron A-Series Processor",
+        OpteronX3000 = 0x6A => "AMD Opteron X3000 Series APU",
+        Zen = 0x6B => "AMD Zen Processor Family",
+        Hobbit = 0x70 => "Hobbit Family",
+        CrusoeTm5000 = 0x78 => "Crusoe TM5000 Family",
+        CrusoeTm3000 = 0x79 => "Crusoe TM3000 Family",
+        EfficeonTm8000 = 0x7A => "Efficeon TM8000 Family",
+        Weitek = 0x80 => "Weitek",
+        Itanium = 0x82 => "Itanium processor",
+        Athlon64 = 0x83 => "AMD Athlon 64 Processor Family",
+        Opteron = 0x84 => "AMD Opteron Processor Family",
+        Sempron = 0x85 => "AMD Sempron Processor Family",
+        Turion64Mobile = 0x86 => "AMD Turion 64 Mobile Technology",
+        DualCoreOpteron = 0x87 => "Dual-Core AMD Opteron Processor Family",
+        Athlon64X2DualCore = 0x88 => "AMD Athlon 64 X2 Dual-Core Processor Family",
+        Turion64X2Mobile = 0x89 => "AMD Turion 64 X2 Mobile Technology",
+        QuadCoreOpteron = 0x8A => "Quad-Core AMD Opteron Processor Family",
+        ThirdGenerationOpteron = 0x8B => "Third-Generation AMD Opteron Processor Family",
+        PhenomFxQuadCore = 0x8C => "AMD Phenom FX Quad-Core Processor Family",
+        PhenomX4QuadCore = 0x8D => "AMD Phenom X4 Quad-Core Processor Family",
+        PhenomX2DualCore = 0x8E => "AMD Phenom X2 Dual-Core Processor Family",
+        AthlonX2DualCore = 0x8F => "AMD Athlon X2 Dual-Core Processor Family",
+        PaRisc = 0x90 => "PA-RISC Family",
+        PaRisc8500 = 0x91 => "PA-RISC 8500",
+        PaRisc8000 = 0x92 => "PA-RISC 8000",
+        PaRisc7300Lc = 0x93 => "PA-RISC 7300LC",
+        PaRisc7200 = 0x94 => "PA-RISC 7200",
+        PaRisc7100Lc = 0x95 => "PA-RISC 7100LC",
+        PaRisc7100 = 0x96 => "PA-RISC 7100",
+        V30 = 0xA0 => "V30 Family",
+        QuadCoreXeon3200 = 0xA1 => "Quad-Core Intel Xeon processor 3200 Series",
+        DualCoreXeon3000 = 0xA2 => "Dual-Core Intel Xeon processor 3000 Series",
+        QuadCoreXeon5300 = 0xA3 => "Quad-Core Intel Xeon processor 5300 Series",
+        DualCoreXeon5100 = 0xA4 => "Dual-Core Intel Xeon processor 5100 Series",
+        DualCoreXeon5000 = 0xA5 => "Dual-Core Intel Xeon processor 5000 Series",
+        DualCoreXeonLv = 0xA6 => "Dual-Core Intel Xeon processor LV",
+        DualCoreXeonUlv = 0xA7 => "Dual-Core Intel Xeon processor ULV",
+        DualCoreXeon7100 = 0xA8 => "Dual-Core Intel Xeon processor 7100 Series",
+        QuadCoreXeon5400 = 0xA9 => "Quad-Core Intel Xeon processor 5400 Series",
+        QuadCoreXeon = 0xAA => "Quad-Core Intel Xeon processor",
+        DualCoreXeon5200 = 0xAB => "Dual-Core Intel Xeon processor 5200 Series",
+        DualCoreXeon7200 = 0xAC => "Dual-Core Intel Xeon processor 7200 Series",
+        QuadCoreXeon7300 = 0xAD => "Quad-Core Intel Xeon processor 7300 Series",
+        QuadCoreXeon7400 = 0xAE => "Quad-Core Intel Xeon processor 7400 Series",
+        MultiCoreXeon7400 = 0xAF => "Multi-Core Intel Xeon processor 7400 Series",
+        PentiumIIIXeon = 0xB0 => "Pentium III Xeon processor",
+        PentiumIIISpeedStep = 0xB1 => "Pentium III Processor with Intel SpeedStep Technology",
+        Pentium4 = 0xB2 => "Pentium 4 Processor",
+        Xeon = 0xB3 => "Intel Xeon processor",
+        As400 = 0xB4 => "AS400 Family",
+        XeonMp = 0xB5 => "Intel Xeon processor MP",
+        AthlonXp = 0xB6 => "AMD Athlon XP Processor Family",
+        AthlonMp = 0xB7 => "AMD Athlon MP Processor Family",
+        Itanium2 = 0xB8 => "Intel Itanium 2 processor",
+        PentiumM = 0xB9 => "Intel Pentium M processor",
+        CeleronD = 0xBA => "Intel Celeron D processor",
+        PentiumD = 0xBB => "Intel Pentium D processor",
+        PentiumExtremeEdition = 0xBC => "Intel Pentium Processor Extreme Edition",
+        CoreSolo = 0xBD => "Intel Core Solo Processor",
+        Core2Duo = 0xBF => "Intel Core 2 Duo Processor",
+        Core2Solo = 0xC0 => "Intel Core 2 Solo processor",
+        Core2Extreme = 0xC1 => "Intel Core 2 Extreme processor",
+        Core2Quad = 0xC2 => "Intel Core 2 Quad processor",
+        Core2ExtremeMobile = 0xC3 => "Intel Core 2 Extreme mobile processor",
+        Core2DuoMobile = 0xC4 => "Intel Core 2 Duo mobile processor",
+        Core2SoloMobile = 0xC5 => "Intel Core 2 Solo mobile processor",
+        CoreI7 = 0xC6 => "Intel Core i7 processor",
+        DualCoreCeleron = 0xC7 => "Dual-Core Intel Celeron processor",
+        Ibm390 = 0xC8 => "IBM390 Family",
+        G4 = 0xC9 => "G4",
+        G5 = 0xCA => "G5",
+        Esa390G6 = 0xCB => "ESA/390 G6",
+        ZArchitecture = 0xCC => "z/Architecture base",
+        CoreI5 = 0xCD => "Intel Core i5 processor",
+        CoreI3 = 0xCE => "Intel Core i3 processor",
+        CoreI9 = 0xCF => "Intel Core i9 processor",
+        XeonD = 0xD0 => "Intel Xeon D Processor family",
+        ViaC7M = 0xD2 => "VIA C7-M Processor Family",
+        ViaC7D = 0xD3 => "VIA C7-D Processor Family",
+        ViaC7 = 0xD4 => "VIA C7 Processor Family",
+        ViaEden = 0xD5 => "VIA Eden Processor Family",
+        MultiCoreXeon = 0xD6 => "Multi-Core Intel Xeon processor",
+        DualCoreXeon3xxx = 0xD7 => "Dual-Core Intel Xeon processor 3xxx Series",
+        QuadCoreXeon3xxx = 0xD8 => "Quad-Core Intel Xeon processor 3xxx Series",
+        ViaNano = 0xD9 => "VIA Nano Processor Family",
+        DualCoreXeon5xxx = 0xDA => "Dual-Core Intel Xeon processor 5xxx Series",
+        QuadCoreXeon5xxx = 0xDB => "Quad-Core Intel Xeon processor 5xxx Series",
+        DualCoreXeon7xxx = 0xDD => "Dual-Core Intel Xeon processor 7xxx Series",
+        QuadCoreXeon7xxx = 0xDE => "Quad-Core Intel Xeon processor 7xxx Series",
+        MultiCoreXeon7xxx = 0xDF => "Multi-Core Intel Xeon processor 7xxx Series",
+        MultiCoreXeon3400 = 0xE0 => "Multi-Core Intel Xeon processor 3400 Series",
+        Opteron3000 = 0xE4 => "AMD Opteron 3000 Series Processor",
+        SempronII = 0xE5 => "AMD Sempron II Processor",
+        EmbeddedOpteronQuadCore = 0xE6 => "Embedded AMD Opteron Quad-Core Processor Family",
+        PhenomTripleCore = 0xE7 => "AMD Phenom Triple-Core Processor Family",
+        TurionUltraDualCoreMobile = 0xE8 => "AMD Turion Ultra Dual-Core Mobile Processor Family",
+        TurionDualCoreMobile = 0xE9 => "AMD Turion Dual-Core Mobile Processor Family",
+        AthlonDualCore = 0xEA => "AMD Athlon Dual-Core Processor Family",
+        SempronSi = 0xEB => "AMD Sempron SI Processor Family",
+        PhenomII = 0xEC => "AMD Phenom II Processor Family",
+        AthlonII = 0xED => "AMD Athlon II Processor Family",
+        SixCoreOpteron = 0xEE => "Six-Core AMD Opteron Processor Family",
+        SempronM = 0xEF => "AMD Sempron M Processor Family",
+        I860 = 0xFA => "i860",
+        I960 = 0xFB => "i960",
+        ArmV7 = 0x100 => "ARMv7",
+        ArmV8 = 0x101 => "ARMv8",
+        ArmV9 = 0x102 => "ARMv9",
+        Sh3 = 0x104 => "SH-3",
+        Sh4 = 0x105 => "SH-4",
+        Arm = 0x118 => "ARM",
+        StrongArm = 0x119 => "StrongARM",
+        Cyrix6x86 = 0x12C => "6x86",
+        MediaGx = 0x12D => "MediaGX",
+        Mii = 0x12E => "MII",
+        WinChip = 0x140 => "WinChip",
+        Dsp = 0x15E => "DSP",
+        VideoProcessor = 0x1F4 => "Video Processor",
+        RiscVRv32 = 0x200 => "RISC-V RV32",
+        RiscVRv64 = 0x201 => "RISC-V RV64",
+        RiscVRv128 = 0x202 => "RISC-V RV128",
+        LoongArch = 0x258 => "LoongArch",
+        Loongson1 = 0x259 => "Loongson 1 Processor Family",
+        Loongson2 = 0x25A => "Loongson 2 Processor Family",
+        Loongson3 = 0x25B => "Loongson 3 Processor Family",
+        Loongson2K = 0x25C => "Loongson 2K Processor Family",
+        Loongson3A = 0x25D => "Loongson 3A Processor Family",
+        Loongson3B = 0x25E => "Loongson 3B Processor Family",
+        Loongson3C = 0x25F => "Loongson 3C Processor Family",
+        Loongson3D = 0x260 => "Loongson 3D Processor Family",
+        Loongson3E = 0x261 => "Loongson 3E Processor Family",
+        DualCoreLoongson2K2xxx = 0x262 => "Dual-Core Loongson 2K Processor 2xxx Series",
+        QuadCoreLoongson3A5xxx = 0x26C => "Quad-Core Loongson 3A Processor 5xxx Series",
+        MultiCoreLoongson3A5xxx = 0x26D => "Multi-Core Loongson 3A Processor 5xxx Series",
+        QuadCoreLoongson3B5xxx = 0x26E => "Quad-Core Loongson 3B Processor 5xxx Series",
+        MultiCoreLoongson3B5xxx = 0x26F => "Multi-Core Loongson 3B Processor 5xxx Series",
+        MultiCoreLoongson3C5xxx = 0x270 => "Multi-Core Loongson 3C Processor 5xxx Series",
+        MultiCoreLoongson3D5xxx = 0x271 => "Multi-Core Loongson 3D Processor 5xxx Series",
+    }
+}
+
+impl ProcessorFamily {
+    /// The architecture of processors in this family.
+    ///
+    /// Returns `None` for families of other or unknown architectures.
+    pub fn architecture(&self) -> Option<ProcessorArchitecture> {
+        if let ProcessorFamily::Undefined(_) = *self {
+            return None;
+        }
+
+        match u16::from(*self) {
+            0x03..=0x16
+            | 0x18..=0x1D
+            | 0x1F
+            | 0x28..=0x2F
+            | 0x38..=0x3F
+            | 0x46..=0x4F
+            | 0x66..=0x6B
+            | 0x78..=0x7A
+            | 0x83..=0x8F
+            | 0xA0..=0xB3
+            | 0xB5..=0xB7
+            | 0xB9..=0xC7
+            | 0xCD..=0xE0
+            | 0xE4..=0xEF
+            | 0x12C..=0x12E
+            | 0x140 => Some(ProcessorArchitecture::X86),
+            0x20..=0x27 | 0xC9 | 0xCA => Some(ProcessorArchitecture::Power),
+            0x100..=0x102 | 0x118 | 0x119 => Some(ProcessorArchitecture::Arm),
+            0x200..=0x202 => Some(ProcessorArchitecture::RiscV),
+            0x258..=0x271 => Some(ProcessorArchitecture::LoongArch),
+            _ => None,
+        }
+    }
+
+    /// Returns true if this is a family of x86 processors.
+    pub fn is_x86(&self) -> bool {
+        self.architecture() == Some(ProcessorArchitecture::X86)
+    }
+
+    /// Returns true if this is a family of ARM processors.
+    pub fn is_arm(&self) -> bool {
+        self.architecture() == Some(ProcessorArchitecture::Arm)
+    }
+}
+
+/// Architectures grouping processor families.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum ProcessorArchitecture {
+    /// Intel x86 and compatible processors, from any vendor.
+    X86,
+    /// ARM processors.
+    Arm,
+    /// RISC-V processors.
+    RiscV,
+    /// LoongArch and Loongson processors.
+    LoongArch,
+    /// POWER and PowerPC processors.
+    Power,
+}
@@ -0,0 +1,248 @@
+//! Decoding of the processor ID field.
+
+use {read_u16, read_u32};
+
+/// Raw processor identification data.
+///
+/// The format of this field depends on the processor family.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct ProcessorId(pub [u8; 8]);
+
+impl ProcessorId {
+    /// The raw bytes of the ID.
+    pub fn as_bytes(&self) -> &[u8; 8] {
+        &self.0
+    }
+
+    /// The first double word of the ID.
+    pub fn low(&self) -> u32 {
+        read_u32(&self.0, 0)
+    }
+
+    /// The second double word of the ID.
+    pub fn high(&self) -> u32 {
+        read_u32(&self.0, 4)
+    }
+
+    /// Interprets the ID as an x86 CPUID signature and feature flags.
+    pub fn x86(&self) -> X86ProcessorId {
+        X86ProcessorId {
+            signature: self.low(),
+            features: X86Features::from_bits_truncate(self.high()),
+        }
+    }
+
+    /// Interprets the ID as the signature of an x86 processor which does not support CPUID.
+    ///
+    /// Such processors only report the value of DX after reset, in the first word.
+    pub fn x86_legacy(&self) -> u16 {
+        read_u16(&self.0, 0)
+    }
+
+    /// Interprets the ID as the main ID register of an ARM processor.
+    pub fn arm_midr(&self) -> Midr {
+        Midr(self.low())
+    }
+
+    /// Interprets the ID as the SoC ID of an ARM processor.
+    pub fn arm_soc_id(&self) -> ArmSocId {
+        ArmSocId {
+            version: self.low(),
+            revision: self.high(),
+        }
+    }
+}
+
+/// A processor ID decoded according to the processor family.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum DecodedProcessorId {
+    /// An x86 processor.
+    X86(X86ProcessorId),
+    /// An x86 processor which does not support CPUID, reporting the signature word
+    /// it puts in DX after reset.
+    X86Legacy(u16),
+    /// An ARM processor, reporting its main ID register.
+    ArmMidr(Midr),
+    /// An ARM processor, reporting its SoC ID.
+    ArmSocId(ArmSocId),
+    /// A processor family for which the format is not known.
+    Other(ProcessorId),
+}
+
+/// ID of an x86 processor, as returned by CPUID with EAX set to 1.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct X86ProcessorId {
+    /// Processor signature, from EAX.
+    pub signature: u32,
+    /// Feature flags, from EDX.
+    pub features: X86Features,
+}
+
+impl X86ProcessorId {
+    /// Stepping ID.
+    pub fn stepping(&self) -> u8 {
+        (self.signature & 0xF) as u8
+    }
+
+    /// Model number, without the extended model.
+    pub fn model(&self) -> u8 {
+        ((self.signature >> 4) & 0xF) as u8
+    }
+
+    /// Family code, without the extended family.
+    pub fn family(&self) -> u8 {
+        ((self.signature >> 8) & 0xF) as u8
+    }
+
+    /// Processor type.
+    pub fn processor_type(&self) -> u8 {
+        ((self.signature >> 12) & 0x3) as u8
+    }
+
+    /// Extended model.
+    pub fn extended_model(&self) -> u8 {
+        ((self.signature >> 16) & 0xF) as u8
+    }
+
+    /// Extended family.
+    pub fn extended_family(&self) -> u8 {
+        ((self.signature >> 20) & 0xFF) as u8
+    }
+
+    /// Family of the processor, combining the family and extended family.
+    pub fn effective_family(&self) -> u16 {
+        match self.family() {
+            0xF => 0xF + u16::from(self.extended_family()),
+            family => u16::from(family),
+        }
+    }
+
+    /// Model of the processor, combining the model and extended model.
+    pub fn effective_model(&self) -> u8 {
+        match self.family() {
+            0x6 | 0xF => self.extended_model() << 4 | self.model(),
+            _ => self.model(),
+        }
+    }
+}
+
+bitflags! {
+    /// Features of an x86 processor, as reported in EDX by CPUID with EAX set to 1.
+    pub struct X86Features: u32 {
+        /// Floating-point unit on-chip.
+        const FPU = 1 << 0;
+        /// Virtual mode extension.
+        const VME = 1 << 1;
+        /// Debugging extension.
+        const DE = 1 << 2;
+        /// Page size extension.
+        const PSE = 1 << 3;
+        /// Time stamp counter.
+        const TSC = 1 << 4;
+        /// Model specific registers.
+        const MSR = 1 << 5;
+        /// Physical address extension.
+        const PAE = 1 << 6;
+        /// Machine check exception.
+        const MCE = 1 << 7;
+        /// CMPXCHG8 instruction supported.
+        const CX8 = 1 << 8;
+        /// On-chip APIC.
+        const APIC = 1 << 9;
+        /// Fast system call.
+        const SEP = 1 << 11;
+        /// Memory type range registers.
+        const MTRR = 1 << 12;
+        /// Page global enable.
+        const PGE = 1 << 13;
+        /// Machine check architecture.
+        const MCA = 1 << 14;
+        /// Conditional move instruction supported.
+        const CMOV = 1 << 15;
+        /// Page attribute table.
+        const PAT = 1 << 16;
+        /// 36-bit page size extension.
+        const PSE36 = 1 << 17;
+        /// Processor serial number present and enabled.
+        const PSN = 1 << 18;
+        /// CLFLUSH instruction supported.
+        const CLFSH = 1 << 19;
+        /// Debug store.
+        const DS = 1 << 21;
+        /// ACPI supported.
+        const ACPI = 1 << 22;
+        /// MMX technology supported.
+        const MMX = 1 << 23;
+        /// FXSAVE and FXSTOR instructions supported.
+        const FXSR = 1 << 24;
+        /// Streaming SIMD extensions.
+        const SSE = 1 << 25;
+        /// Streaming SIMD extensions 2.
+        const SSE2 = 1 << 26;
+        /// Self-snoop.
+        const SS = 1 << 27;
+        /// Multi-threading.
+        const HTT = 1 << 28;
+        /// Thermal monitor supported.
+        const TM = 1 << 29;
+        /// Pending break enabled.
+        const PBE = 1 << 31;
+    }
+}
+
+/// The main ID register (MIDR) of an ARM processor.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Midr(pub u32);
+
+impl Midr {
+    /// Implementer code, assigned by ARM.
+    pub fn implementer(&self) -> u8 {
+        (self.0 >> 24) as u8
+    }
+
+    /// Variant number, usually the major revision of the product.
+    pub fn variant(&self) -> u8 {
+        ((self.0 >> 20) & 0xF) as u8
+    }
+
+    /// Architecture code.
+    pub fn architecture(&self) -> u8 {
+        ((self.0 >> 16) & 0xF) as u8
+    }
+
+    /// Part number, defined by the implementer.
+    pub fn part_number(&self) -> u16 {
+        ((self.0 >> 4) & 0xFFF) as u16
+    }
+
+    /// Revision number, usually the minor revision of the product.
+    pub fn revision(&self) -> u8 {
+        (self.0 & 0xF) as u8
+    }
+}
+
+/// The SoC ID of an ARM processor, as returned by the `SMCCC_ARCH_SOC_ID` call.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct ArmSocId {
+    /// SoC version.
+    pub version: u32,
+    /// SoC revision.
+    pub revision: u32,
+}
+
+impl ArmSocId {
+    /// The JEP-106 bank index of the SiP, which is its number of continuation codes.
+    pub fn jep106_bank(&self) -> u8 {
+        ((self.version >> 24) & 0x7F) as u8
+    }
+
+    /// The JEP-106 identification code of the SiP, within its bank.
+    pub fn jep106_id(&self) -> u8 {
+        ((self.version >> 16) & 0x7F) as u8
+    }
+
+    /// Implementation-defined ID of the SoC.
+    pub fn soc_id(&self) -> u16 {
+        self.version as u16
+    }
+}
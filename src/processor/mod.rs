@@ -0,0 +1,438 @@
+//! Processor information (type 4).
+
+mod family;
+mod id;
+mod upgrade;
+
+pub use self::family::{ProcessorArchitecture, ProcessorFamily};
+pub use self::id::{ArmSocId, DecodedProcessorId, Midr, ProcessorId, X86Features, X86ProcessorId};
+pub use self::upgrade::ProcessorUpgrade;
+
+use table::RawStructure;
+use Type;
+
+/// Safe view of a processor information structure.
+#[derive(Debug, Copy, Clone)]
+pub struct ProcessorInformationView<'a> {
+    raw: RawStructure<'a>,
+}
+
+impl<'a> ProcessorInformationView<'a> {
+    /// Interprets a raw structure as processor information.
+    ///
+    /// Returns `None` if the structure has a different type or is too short.
+    pub fn new(raw: RawStructure<'a>) -> Option<Self> {
+        if raw.ty() != Type::ProcessorInformation || raw.formatted().len() < 0x1A {
+            return None;
+        }
+        Some(ProcessorInformationView { raw })
+    }
+
+    /// The underlying structure.
+    pub fn raw(&self) -> RawStructure<'a> {
+        self.raw
+    }
+
+    /// Designation of the processor's socket, as printed on the board.
+    pub fn socket_designation(&self) -> Option<&'a str> {
+        self.raw.string_lossy(0x04)
+    }
+
+    /// Type of the processor.
+    pub fn processor_type(&self) -> ProcessorType {
+        ProcessorType::from(self.raw.byte(0x05).unwrap_or(0))
+    }
+
+    /// Processor family.
+    ///
+    /// If the family byte is 0xFE, the value is taken from the
+    /// Processor Family 2 field of SMBIOS 2.6+.
+    pub fn family(&self) -> ProcessorFamily {
+        let family = match self.raw.byte(0x06).unwrap_or(0) {
+            0xFE => self.raw.word(0x28).unwrap_or(0xFE),
+            family => u16::from(family),
+        };
+        ProcessorFamily::from(family)
+    }
+
+    /// Processor manufacturer.
+    pub fn manufacturer(&self) -> Option<&'a str> {
+        self.raw.string_lossy(0x07)
+    }
+
+    /// Raw processor identification data.
+    ///
+    /// Its format depends on the processor family, see `decoded_id`.
+    pub fn processor_id(&self) -> ProcessorId {
+        let mut id = [0; 8];
+        id.copy_from_slice(&self.raw.formatted()[0x08..0x10]);
+        ProcessorId(id)
+    }
+
+    /// Processor identification data, decoded according to the processor family.
+    ///
+    /// Processors older than the Pentium which do not support CPUID only report a signature word.
+    /// ARM processors report their SoC ID if the `ARM64_SOC_ID` characteristic is set,
+    /// or their main ID register otherwise.
+    pub fn decoded_id(&self) -> DecodedProcessorId {
+        let id = self.processor_id();
+        let family = self.family();
+
+        match family {
+            ProcessorFamily::I8086 | ProcessorFamily::I80286 | ProcessorFamily::Intel386 => {
+                DecodedProcessorId::X86Legacy(id.x86_legacy())
+            }
+            // Later 486 processors support CPUID, older ones only fill the first word.
+            ProcessorFamily::Intel486 if id.as_bytes()[2..].iter().all(|&b| b == 0) => {
+                DecodedProcessorId::X86Legacy(id.x86_legacy())
+            }
+            _ if family.is_x86() => DecodedProcessorId::X86(id.x86()),
+            _ if family.is_arm() => {
+                let soc_id = self
+                    .characteristics()
+                    .unwrap_or_else(ProcessorCharacteristics::empty)
+                    .contains(ProcessorCharacteristics::ARM64_SOC_ID);
+                if soc_id {
+                    DecodedProcessorId::ArmSocId(id.arm_soc_id())
+                } else {
+                    DecodedProcessorId::ArmMidr(id.arm_midr())
+                }
+            }
+            _ => DecodedProcessorId::Other(id),
+        }
+    }
+
+    /// Processor version.
+    pub fn version(&self) -> Option<&'a str> {
+        self.raw.string_lossy(0x10)
+    }
+
+    /// Voltage of the processor.
+    pub fn voltage(&self) -> ProcessorVoltage {
+        let voltage = self.raw.byte(0x11).unwrap_or(0);
+        if voltage & 0x80 != 0 {
+            ProcessorVoltage::Current(voltage & 0x7F)
+        } else {
+            ProcessorVoltage::Legacy(LegacyVoltages::from_bits_truncate(voltage))
+        }
+    }
+
+    /// External clock frequency, in MHz.
+    ///
+    /// Returns `None` if the frequency is unknown.
+    pub fn external_clock(&self) -> Option<u16> {
+        self.raw.word(0x12).and_then(non_zero)
+    }
+
+    /// Maximum speed supported by the system, in MHz.
+    ///
+    /// Returns `None` if the speed is unknown.
+    pub fn max_speed(&self) -> Option<u16> {
+        self.raw.word(0x14).and_then(non_zero)
+    }
+
+    /// Speed of the processor at boot time, in MHz.
+    ///
+    /// Returns `None` if the speed is unknown.
+    pub fn current_speed(&self) -> Option<u16> {
+        self.raw.word(0x16).and_then(non_zero)
+    }
+
+    /// Returns true if the processor socket is populated.
+    pub fn socket_populated(&self) -> bool {
+        self.raw.byte(0x18).unwrap_or(0) & 0x40 != 0
+    }
+
+    /// Status of the processor.
+    pub fn status(&self) -> ProcessorStatus {
+        ProcessorStatus::from(self.raw.byte(0x18).unwrap_or(0) & 0x07)
+    }
+
+    /// Processor upgrade or socket type.
+    pub fn upgrade(&self) -> ProcessorUpgrade {
+        ProcessorUpgrade::from(self.raw.byte(0x19).unwrap_or(0))
+    }
+
+    /// Handle of the primary (level 1) cache structure.
+    ///
+    /// Only supported by SMBIOS 2.1+, and `None` if the processor has no such cache.
+    pub fn l1_cache_handle(&self) -> Option<u16> {
+        self.cache_handle(0x1A)
+    }
+
+    /// Handle of the secondary (level 2) cache structure.
+    ///
+    /// Only supported by SMBIOS 2.1+, and `None` if the processor has no such cache.
+    pub fn l2_cache_handle(&self) -> Option<u16> {
+        self.cache_handle(0x1C)
+    }
+
+    /// Handle of the tertiary (level 3) cache structure.
+    ///
+    /// Only supported by SMBIOS 2.1+, and `None` if the processor has no such cache.
+    pub fn l3_cache_handle(&self) -> Option<u16> {
+        self.cache_handle(0x1E)
+    }
+
+    /// Serial number of the processor.
+    ///
+    /// Only supported by SMBIOS 2.3+.
+    pub fn serial_number(&self) -> Option<&'a str> {
+        self.raw.string_lossy(0x20)
+    }
+
+    /// Asset tag of the processor.
+    ///
+    /// Only supported by SMBIOS 2.3+.
+    pub fn asset_tag(&self) -> Option<&'a str> {
+        self.raw.string_lossy(0x21)
+    }
+
+    /// Part number of the processor.
+    ///
+    /// Only supported by SMBIOS 2.3+.
+    pub fn part_number(&self) -> Option<&'a str> {
+        self.raw.string_lossy(0x22)
+    }
+
+    /// Number of cores per processor socket.
+    ///
+    /// Only supported by SMBIOS 2.5+, and `None` if the number is unknown.
+    /// Counts above 255 are read from the Core Count 2 field of SMBIOS 3.0+.
+    pub fn core_count(&self) -> Option<u16> {
+        self.count(0x23, 0x2A)
+    }
+
+    /// Number of enabled cores per processor socket.
+    ///
+    /// Only supported by SMBIOS 2.5+, and `None` if the number is unknown.
+    /// Counts above 255 are read from the Core Enabled 2 field of SMBIOS 3.0+.
+    pub fn core_enabled(&self) -> Option<u16> {
+        self.count(0x24, 0x2C)
+    }
+
+    /// Number of threads per processor socket.
+    ///
+    /// Only supported by SMBIOS 2.5+, and `None` if the number is unknown.
+    /// Counts above 255 are read from the Thread Count 2 field of SMBIOS 3.0+.
+    pub fn thread_count(&self) -> Option<u16> {
+        self.count(0x25, 0x2E)
+    }
+
+    /// Number of enabled threads per processor socket.
+    ///
+    /// Only supported by SMBIOS 3.6+, and `None` if the number is unknown.
+    pub fn thread_enabled(&self) -> Option<u16> {
+        self.raw.word(0x30).and_then(valid_count)
+    }
+
+    /// Processor characteristics.
+    ///
+    /// Only supported by SMBIOS 2.5+.
+    pub fn characteristics(&self) -> Option<ProcessorCharacteristics> {
+        self.raw
+            .word(0x26)
+            .map(ProcessorCharacteristics::from_bits_truncate)
+    }
+
+    fn cache_handle(&self, offset: usize) -> Option<u16> {
+        self.raw.word(offset).and_then(|handle| match handle {
+            0xFFFF => None,
+            handle => Some(handle),
+        })
+    }
+
+    fn count(&self, offset: usize, offset2: usize) -> Option<u16> {
+        match self.raw.byte(offset)? {
+            0 => None,
+            0xFF => match self.raw.word(offset2) {
+                Some(count) => valid_count(count),
+                None => Some(0xFF),
+            },
+            count => Some(u16::from(count)),
+        }
+    }
+}
+
+fn non_zero(value: u16) -> Option<u16> {
+    if value == 0 {
+        None
+    } else {
+        Some(value)
+    }
+}
+
+/// Filters out the unknown (0) and reserved (0xFFFF) values of a 16-bit count.
+fn valid_count(count: u16) -> Option<u16> {
+    match count {
+        0 | 0xFFFF => None,
+        count => Some(count),
+    }
+}
+
+/// Voltage of a processor.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ProcessorVoltage {
+    /// The voltages supported by the processor socket.
+    Legacy(LegacyVoltages),
+    /// The current voltage of the processor, in tenths of a volt.
+    Current(u8),
+}
+
+bitflags! {
+    /// Voltages supported by a processor or memory module, in legacy mode.
+    pub struct LegacyVoltages: u8 {
+        /// 5V.
+        const V5_0 = 1 << 0;
+        /// 3.3V.
+        const V3_3 = 1 << 1;
+        /// 2.9V.
+        const V2_9 = 1 << 2;
+    }
+}
+
+bitflags! {
+    /// Processor characteristics.
+    pub struct ProcessorCharacteristics: u16 {
+        /// Characteristics are unknown.
+        const UNKNOWN = 1 << 1;
+        /// 64-bit capable.
+        const CAPABLE_64_BIT = 1 << 2;
+        /// Multi-core.
+        const MULTI_CORE = 1 << 3;
+        /// Hardware thread.
+        const HARDWARE_THREAD = 1 << 4;
+        /// Execute protection.
+        const EXECUTE_PROTECTION = 1 << 5;
+        /// Enhanced virtualization.
+        const ENHANCED_VIRTUALIZATION = 1 << 6;
+        /// Power/performance control.
+        const POWER_PERFORMANCE_CONTROL = 1 << 7;
+        /// 128-bit capable.
+        const CAPABLE_128_BIT = 1 << 8;
+        /// The processor ID contains an Arm64 SoC ID.
+        const ARM64_SOC_ID = 1 << 9;
+    }
+}
+
+spec_enum! {
+    /// Type of a processor.
+    pub enum ProcessorType: u8 {
+        Other = 0x01 => "Other",
+        Unknown = 0x02 => "Unknown",
+        CentralProcessor = 0x03 => "Central Processor",
+        MathProcessor = 0x04 => "Math Processor",
+        DspProcessor = 0x05 => "DSP Processor",
+        VideoProcessor = 0x06 => "Video Processor",
+    }
+}
+
+spec_enum! {
+    /// Status of a processor.
+    pub enum ProcessorStatus: u8 {
+        Unknown = 0x00 => "Unknown",
+        Enabled = 0x01 => "CPU Enabled",
+        DisabledByUser = 0x02 => "CPU Disabled by User through BIOS Setup",
+        DisabledByBios = 0x03 => "CPU Disabled by BIOS (POST Error)",
+        Idle = 0x04 => "CPU is Idle, waiting to be enabled",
+        Other = 0x07 => "Other",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use table::TestStructure;
+
+    fn processor(len: u8) -> TestStructure {
+        TestStructure::new(4, len)
+    }
+
+    #[test]
+    fn core_count_above_255() {
+        let data = processor(0x30)
+            .field(0x23, &[0xFF])
+            .field(0x2A, &[0x2C, 0x01]);
+        let cpu = ProcessorInformationView::new(data.raw()).unwrap();
+        assert_eq!(cpu.core_count(), Some(300));
+    }
+
+    #[test]
+    fn core_count_before_3_0() {
+        let data = processor(0x2A).field(0x23, &[0xFF]);
+        let cpu = ProcessorInformationView::new(data.raw()).unwrap();
+        assert_eq!(cpu.core_count(), Some(255));
+    }
+
+    #[test]
+    fn core_count_unknown() {
+        let data = processor(0x30);
+        let cpu = ProcessorInformationView::new(data.raw()).unwrap();
+        assert_eq!(cpu.core_count(), None);
+
+        let data = processor(0x30)
+            .field(0x23, &[0xFF])
+            .field(0x2A, &[0xFF, 0xFF]);
+        let cpu = ProcessorInformationView::new(data.raw()).unwrap();
+        assert_eq!(cpu.core_count(), None);
+
+        let data = processor(0x1A);
+        let cpu = ProcessorInformationView::new(data.raw()).unwrap();
+        assert_eq!(cpu.core_count(), None);
+    }
+
+    #[test]
+    fn family_2() {
+        let data = processor(0x2A)
+            .field(0x06, &[0xFE])
+            .field(0x28, &[0x01, 0x01]);
+        let cpu = ProcessorInformationView::new(data.raw()).unwrap();
+        assert_eq!(cpu.family(), ProcessorFamily::ArmV8);
+        assert!(cpu.family().is_arm());
+    }
+
+    #[test]
+    fn legacy_x86_id() {
+        let data = processor(0x1A)
+            .field(0x06, &[0x05])
+            .field(0x08, &[0x08, 0x03]);
+        let cpu = ProcessorInformationView::new(data.raw()).unwrap();
+        assert_eq!(cpu.decoded_id(), DecodedProcessorId::X86Legacy(0x0308));
+    }
+
+    #[test]
+    fn cpuid_x86_id() {
+        let data = processor(0x1A)
+            .field(0x06, &[0x0B])
+            .field(0x08, &[0x43, 0x05, 0, 0, 0xFF, 0xFB, 0x8B, 0x00]);
+        let cpu = ProcessorInformationView::new(data.raw()).unwrap();
+        match cpu.decoded_id() {
+            DecodedProcessorId::X86(id) => {
+                assert_eq!((id.family(), id.model(), id.stepping()), (5, 4, 3));
+                assert!(id.features.contains(X86Features::FPU));
+            }
+            id => panic!("unexpected ID {:?}", id),
+        }
+    }
+
+    #[test]
+    fn cpuid_486_id() {
+        let data = processor(0x1A)
+            .field(0x06, &[0x06])
+            .field(0x08, &[0x80, 0x04, 0, 0, 0x03, 0x00, 0x00, 0x00]);
+        let cpu = ProcessorInformationView::new(data.raw()).unwrap();
+        match cpu.decoded_id() {
+            DecodedProcessorId::X86(id) => {
+                assert_eq!((id.family(), id.model()), (4, 8));
+                assert_eq!(id.features, X86Features::FPU | X86Features::VME);
+            }
+            id => panic!("unexpected ID {:?}", id),
+        }
+
+        let data = processor(0x1A)
+            .field(0x06, &[0x06])
+            .field(0x08, &[0x42, 0x04]);
+        let cpu = ProcessorInformationView::new(data.raw()).unwrap();
+        assert_eq!(cpu.decoded_id(), DecodedProcessorId::X86Legacy(0x0442));
+    }
+}
@@ -0,0 +1,94 @@
+//! Processor upgrade and socket types.
+
+spec_enum! {
+    /// The socket or method used to upgrade a processor.
+    pub enum ProcessorUpgrade: u8 {
+        Other = 0x01 => "Other",
+        Unknown = 0x02 => "Unknown",
+        DaughterBoard = 0x03 => "Daughter Board",
+        ZifSocket = 0x04 => "ZIF Socket",
+        ReplaceablePiggyBack = 0x05 => "Replaceable Piggy Back",
+        None = 0x06 => "None",
+        LifSocket = 0x07 => "LIF Socket",
+        Slot1 = 0x08 => "Slot 1",
+        Slot2 = 0x09 => "Slot 2",
+        Socket370 = 0x0A => "370-pin socket",
+        SlotA = 0x0B => "Slot A",
+        SlotM = 0x0C => "Slot M",
+        Socket423 = 0x0D => "Socket 423",
+        SocketA = 0x0E => "Socket A (Socket 462)",
+        Socket478 = 0x0F => "Socket 478",
+        Socket754 = 0x10 => "Socket 754",
+        Socket940 = 0x11 => "Socket 940",
+        Socket939 = 0x12 => "Socket 939",
+        SocketMpga604 = 0x13 => "Socket mPGA604",
+        SocketLga771 = 0x14 => "Socket LGA771",
+        SocketLga775 = 0x15 => "Socket LGA775",
+        SocketS1 = 0x16 => "Socket S1",
+        SocketAm2 = 0x17 => "Socket AM2",
+        SocketF = 0x18 => "Socket F (1207)",
+        SocketLga1366 = 0x19 => "Socket LGA1366",
+        SocketG34 = 0x1A => "Socket G34",
+        SocketAm3 = 0x1B => "Socket AM3",
+        SocketC32 = 0x1C => "Socket C32",
+        SocketLga1156 = 0x1D => "Socket LGA1156",
+        SocketLga1567 = 0x1E => "Socket LGA1567",
+        SocketPga988a = 0x1F => "Socket PGA988A",
+        SocketBga1288 = 0x20 => "Socket BGA1288",
+        SocketRpga988b = 0x21 => "Socket rPGA988B",
+        SocketBga1023 = 0x22 => "Socket BGA1023",
+        SocketBga1224 = 0x23 => "Socket BGA1224",
+        SocketLga1155 = 0x24 => "Socket LGA1155",
+        SocketLga1356 = 0x25 => "Socket LGA1356",
+        SocketLga2011 = 0x26 => "Socket LGA2011",
+        SocketFs1 = 0x27 => "Socket FS1",
+        SocketFs2 = 0x28 => "Socket FS2",
+        SocketFm1 = 0x29 => "Socket FM1",
+        SocketFm2 = 0x2A => "Socket FM2",
+        SocketLga2011_3 = 0x2B => "Socket LGA2011-3",
+        SocketLga1356_3 = 0x2C => "Socket LGA1356-3",
+        SocketLga1150 = 0x2D => "Socket LGA1150",
+        SocketBga1168 = 0x2E => "Socket BGA1168",
+        SocketBga1234 = 0x2F => "Socket BGA1234",
+        SocketBga1364 = 0x30 => "Socket BGA1364",
+        SocketAm4 = 0x31 => "Socket AM4",
+        SocketLga1151 = 0x32 => "Socket LGA1151",
+        SocketBga1356 = 0x33 => "Socket BGA1356",
+        SocketBga1440 = 0x34 => "Socket BGA1440",
+        SocketBga1515 = 0x35 => "Socket BGA1515",
+        SocketLga3647_1 = 0x36 => "Socket LGA3647-1",
+        SocketSp3 = 0x37 => "Socket SP3",
+        SocketSp3r2 = 0x38 => "Socket SP3r2",
+        SocketLga2066 = 0x39 => "Socket LGA2066",
+        SocketBga1392 = 0x3A => "Socket BGA1392",
+        SocketBga1510 = 0x3B => "Socket BGA1510",
+        SocketBga1528 = 0x3C => "Socket BGA1528",
+        SocketLga4189 = 0x3D => "Socket LGA4189",
+        SocketLga1200 = 0x3E => "Socket LGA1200",
+        SocketLga4677 = 0x3F => "Socket LGA4677",
+        SocketLga1700 = 0x40 => "Socket LGA1700",
+        SocketBga1744 = 0x41 => "Socket BGA1744",
+        SocketBga1781 = 0x42 => "Socket BGA1781",
+        SocketBga1211 = 0x43 => "Socket BGA1211",
+        SocketBga2422 = 0x44 => "Socket BGA2422",
+        SocketLga1211 = 0x45 => "Socket LGA1211",
+        SocketLga2422 = 0x46 => "Socket LGA2422",
+        SocketLga5773 = 0x47 => "Socket LGA5773",
+        SocketBga5773 = 0x48 => "Socket BGA5773",
+        SocketAm5 = 0x49 => "Socket AM5",
+        SocketSp5 = 0x4A => "Socket SP5",
+        SocketSp6 = 0x4B => "Socket SP6",
+        SocketBga883 = 0x4C => "Socket BGA883",
+        SocketBga1190 = 0x4D => "Socket BGA1190",
+        SocketBga4129 = 0x4E => "Socket BGA4129",
+        SocketLga4710 = 0x4F => "Socket LGA4710",
+        SocketLga7529 = 0x50 => "Socket LGA7529",
+        SocketBga1964 = 0x51 => "Socket BGA1964",
+        SocketBga1792 = 0x52 => "Socket BGA1792",
+        SocketBga2049 = 0x53 => "Socket BGA2049",
+        SocketBga2551 = 0x54 => "Socket BGA2551",
+        SocketLga1851 = 0x55 => "Socket LGA1851",
+        SocketBga2114 = 0x56 => "Socket BGA2114",
+        SocketBga2833 = 0x57 => "Socket BGA2833",
+    }
+}
@@ -0,0 +1,455 @@
+//! System slots (type 9).
+
+use table::RawStructure;
+use {read_u16, Type};
+
+/// Safe view of a system slot structure.
+#[derive(Debug, Copy, Clone)]
+pub struct SystemSlotView<'a> {
+    raw: RawStructure<'a>,
+}
+
+impl<'a> SystemSlotView<'a> {
+    /// Interprets a raw structure as a system slot.
+    ///
+    /// Returns `None` if the structure has a different type or is too short.
+    pub fn new(raw: RawStructure<'a>) -> Option<Self> {
+        if raw.ty() != Type::SystemSlot || raw.formatted().len() < 0x0C {
+            return None;
+        }
+        Some(SystemSlotView { raw })
+    }
+
+    /// The underlying structure.
+    pub fn raw(&self) -> RawStructure<'a> {
+        self.raw
+    }
+
+    /// Designation of the slot, as printed on the board.
+    pub fn designation(&self) -> Option<&'a str> {
+        self.raw.string_lossy(0x04)
+    }
+
+    /// Type of the slot.
+    pub fn slot_type(&self) -> SlotType {
+        SlotType::from(self.raw.byte(0x05).unwrap_or(0))
+    }
+
+    /// Width of the slot's data bus.
+    pub fn data_bus_width(&self) -> SlotWidth {
+        SlotWidth::from(self.raw.byte(0x06).unwrap_or(0))
+    }
+
+    /// Usage of the slot.
+    pub fn current_usage(&self) -> SlotUsage {
+        SlotUsage::from(self.raw.byte(0x07).unwrap_or(0))
+    }
+
+    /// Physical length of the slot.
+    pub fn length(&self) -> SlotLength {
+        SlotLength::from(self.raw.byte(0x08).unwrap_or(0))
+    }
+
+    /// ID of the slot, whose meaning depends on the slot type.
+    pub fn slot_id(&self) -> u16 {
+        self.raw.word(0x09).unwrap_or(0)
+    }
+
+    /// First byte of the slot characteristics.
+    pub fn characteristics1(&self) -> SlotCharacteristics1 {
+        SlotCharacteristics1::from_bits_truncate(self.raw.byte(0x0B).unwrap_or(0))
+    }
+
+    /// Second byte of the slot characteristics.
+    ///
+    /// Only supported by SMBIOS 2.1+.
+    pub fn characteristics2(&self) -> Option<SlotCharacteristics2> {
+        self.raw
+            .byte(0x0C)
+            .map(SlotCharacteristics2::from_bits_truncate)
+    }
+
+    /// Address of the device in the slot.
+    ///
+    /// Only supported by SMBIOS 2.6+. For slots which are not on a PCI bus,
+    /// the segment group is set to 0xFFFF, and the bus number and device/function
+    /// byte to 0xFF, which decodes as device 0x1F and function 7.
+    pub fn address(&self) -> Option<PciAddress> {
+        self.raw.bytes(0x0D, 4).map(PciAddress::from_bytes)
+    }
+
+    /// Electrical width of the slot, as a number of lanes.
+    ///
+    /// Only supported by SMBIOS 3.2+.
+    pub fn base_data_bus_width(&self) -> Option<u8> {
+        self.raw.byte(0x11)
+    }
+
+    /// Returns an iterator over the peer devices grouped with the slot's device.
+    ///
+    /// Only supported by SMBIOS 3.2+.
+    pub fn peer_groups(&self) -> PeerGroups<'a> {
+        let count = self.raw.byte(0x12).unwrap_or(0) as usize;
+        let formatted = self.raw.formatted();
+        let start = 0x13.min(formatted.len());
+        let end = (0x13 + 5 * count).min(formatted.len());
+        PeerGroups {
+            bytes: &formatted[start..end],
+        }
+    }
+
+    /// Additional information about the slot, whose meaning depends on the slot type.
+    ///
+    /// For PCI Express slots, this is the generation of the slot.
+    /// Only supported by SMBIOS 3.4+.
+    pub fn slot_information(&self) -> Option<u8> {
+        self.raw.byte(self.tail_offset()?)
+    }
+
+    /// Physical width of the slot.
+    ///
+    /// Only supported by SMBIOS 3.4+.
+    pub fn physical_width(&self) -> Option<SlotWidth> {
+        self.raw.byte(self.tail_offset()? + 1).map(SlotWidth::from)
+    }
+
+    /// Pitch of the slot, the distance to the next slot, in hundredths of a millimeter.
+    ///
+    /// Only supported by SMBIOS 3.4+, and `None` if the pitch is not given.
+    pub fn pitch(&self) -> Option<u16> {
+        self.raw
+            .word(self.tail_offset()? + 2)
+            .and_then(|pitch| match pitch {
+                0 => None,
+                pitch => Some(pitch),
+            })
+    }
+
+    /// Maximum height of a card which can be installed in the slot.
+    ///
+    /// Only supported by SMBIOS 3.5+.
+    pub fn height(&self) -> Option<SlotHeight> {
+        self.raw.byte(self.tail_offset()? + 4).map(SlotHeight::from)
+    }
+
+    /// Offset of the fields following the peer groups.
+    fn tail_offset(&self) -> Option<usize> {
+        self.raw.byte(0x12).map(|count| 0x13 + count as usize * 5)
+    }
+}
+
+/// Address of a PCI device.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct PciAddress {
+    /// Segment group number.
+    pub segment: u16,
+    /// Bus number.
+    pub bus: u8,
+    /// Device number.
+    pub device: u8,
+    /// Function number.
+    pub function: u8,
+}
+
+impl PciAddress {
+    fn from_bytes(bytes: &[u8]) -> Self {
+        PciAddress {
+            segment: read_u16(bytes, 0),
+            bus: bytes[2],
+            device: bytes[3] >> 3,
+            function: bytes[3] & 0x7,
+        }
+    }
+}
+
+/// Iterator over the peer devices of a slot.
+#[derive(Debug, Clone)]
+pub struct PeerGroups<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Iterator for PeerGroups<'a> {
+    type Item = PeerDevice;
+
+    fn next(&mut self) -> Option<PeerDevice> {
+        if self.bytes.len() < 5 {
+            return None;
+        }
+
+        let peer = PeerDevice {
+            address: PciAddress::from_bytes(self.bytes),
+            data_bus_width: self.bytes[4],
+        };
+        self.bytes = &self.bytes[5..];
+
+        Some(peer)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.bytes.len() / 5;
+        (len, Some(len))
+    }
+}
+
+impl<'a> ExactSizeIterator for PeerGroups<'a> {}
+
+/// A device grouped with the device in a slot, such as one sharing its lanes.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct PeerDevice {
+    /// Address of the device.
+    pub address: PciAddress,
+    /// Electrical width of the device, as a number of lanes.
+    pub data_bus_width: u8,
+}
+
+bitflags! {
+    /// First byte of the characteristics of a slot.
+    pub struct SlotCharacteristics1: u8 {
+        /// Characteristics are unknown.
+        const UNKNOWN = 1 << 0;
+        /// Provides 5.0 volts.
+        const V5_0 = 1 << 1;
+        /// Provides 3.3 volts.
+        const V3_3 = 1 << 2;
+        /// The opening of the slot is shared with another slot.
+        const SHARED = 1 << 3;
+        /// PC Card slot supports PC Card-16.
+        const PC_CARD_16 = 1 << 4;
+        /// PC Card slot supports CardBus.
+        const CARD_BUS = 1 << 5;
+        /// PC Card slot supports Zoom Video.
+        const ZOOM_VIDEO = 1 << 6;
+        /// PC Card slot supports Modem Ring Resume.
+        const MODEM_RING_RESUME = 1 << 7;
+    }
+}
+
+bitflags! {
+    /// Second byte of the characteristics of a slot.
+    pub struct SlotCharacteristics2: u8 {
+        /// PCI slot supports Power Management Event (PME#) signal.
+        const PME = 1 << 0;
+        /// Slot supports hot-plug devices.
+        const HOT_PLUG = 1 << 1;
+        /// PCI slot supports SMBus signal.
+        const SMBUS = 1 << 2;
+        /// PCI Express slot supports bifurcation.
+        const BIFURCATION = 1 << 3;
+        /// Slot supports async/surprise removal.
+        const SURPRISE_REMOVAL = 1 << 4;
+        /// Flexbus slot, CXL 1.0 capable.
+        const CXL_1_0 = 1 << 5;
+        /// Flexbus slot, CXL 2.0 capable.
+        const CXL_2_0 = 1 << 6;
+        /// Flexbus slot, CXL 3.0 capable.
+        const CXL_3_0 = 1 << 7;
+    }
+}
+
+spec_enum! {
+    /// Type of a slot.
+    pub enum SlotType: u8 {
+        Other = 0x01 => "Other",
+        Unknown = 0x02 => "Unknown",
+        Isa = 0x03 => "ISA",
+        Mca = 0x04 => "MCA",
+        Eisa = 0x05 => "EISA",
+        Pci = 0x06 => "PCI",
+        PcCard = 0x07 => "PC Card (PCMCIA)",
+        VlVesa = 0x08 => "VL-VESA",
+        Proprietary = 0x09 => "Proprietary",
+        ProcessorCard = 0x0A => "Processor Card Slot",
+        ProprietaryMemoryCard = 0x0B => "Proprietary Memory Card Slot",
+        IoRiserCard = 0x0C => "I/O Riser Card Slot",
+        NuBus = 0x0D => "NuBus",
+        Pci66MHz = 0x0E => "PCI - 66MHz Capable",
+        Agp = 0x0F => "AGP",
+        Agp2x = 0x10 => "AGP 2X",
+        Agp4x = 0x11 => "AGP 4X",
+        PciX = 0x12 => "PCI-X",
+        Agp8x = 0x13 => "AGP 8X",
+        M2Socket1Dp = 0x14 => "M.2 Socket 1-DP (Mechanical Key A)",
+        M2Socket1Sd = 0x15 => "M.2 Socket 1-SD (Mechanical Key E)",
+        M2Socket2 = 0x16 => "M.2 Socket 2 (Mechanical Key B)",
+        M2Socket3 = 0x17 => "M.2 Socket 3 (Mechanical Key M)",
+        MxmTypeI = 0x18 => "MXM Type I",
+        MxmTypeII = 0x19 => "MXM Type II",
+        MxmTypeIIIStandard = 0x1A => "MXM Type III (standard connector)",
+        MxmTypeIIIHe = 0x1B => "MXM Type III (HE connector)",
+        MxmTypeIV = 0x1C => "MXM Type IV",
+        Mxm3TypeA = 0x1D => "MXM 3.0 Type A",
+        Mxm3TypeB = 0x1E => "MXM 3.0 Type B",
+        PciExpressGen2Sff8639 = 0x1F => "PCI Express Gen 2 SFF-8639 (U.2)",
+        PciExpressGen3Sff8639 = 0x20 => "PCI Express Gen 3 SFF-8639 (U.2)",
+        PciExpressMini52PinWithKeepOuts = 0x21 => "PCI Express Mini 52-pin (CEM spec. 2.0) with bottom-side keep-outs",
+        PciExpressMini52PinWithoutKeepOuts = 0x22 => "PCI Express Mini 52-pin (CEM spec. 2.0) without bottom-side keep-outs",
+        PciExpressMini76Pin = 0x23 => "PCI Express Mini 76-pin (CEM spec. 2.0)",
+        PciExpressGen4Sff8639 = 0x24 => "PCI Express Gen 4 SFF-8639 (U.2)",
+        PciExpressGen5Sff8639 = 0x25 => "PCI Express Gen 5 SFF-8639 (U.2)",
+        OcpNic3Sff = 0x26 => "OCP NIC 3.0 Small Form Factor (SFF)",
+        OcpNic3Lff = 0x27 => "OCP NIC 3.0 Large Form Factor (LFF)",
+        OcpNicPrior3 = 0x28 => "OCP NIC Prior to 3.0",
+        CxlFlexbus1 = 0x30 => "CXL Flexbus 1.0",
+        Pc98C20 = 0xA0 => "PC-98/C20",
+        Pc98C24 = 0xA1 => "PC-98/C24",
+        Pc98E = 0xA2 => "PC-98/E",
+        Pc98LocalBus = 0xA3 => "PC-98/Local Bus",
+        Pc98Card = 0xA4 => "PC-98/Card",
+        PciExpress = 0xA5 => "PCI Express",
+        PciExpressX1 = 0xA6 => "PCI Express x1",
+        PciExpressX2 = 0xA7 => "PCI Express x2",
+        PciExpressX4 = 0xA8 => "PCI Express x4",
+        PciExpressX8 = 0xA9 => "PCI Express x8",
+        PciExpressX16 = 0xAA => "PCI Express x16",
+        PciExpressGen2 = 0xAB => "PCI Express Gen 2",
+        PciExpressGen2X1 = 0xAC => "PCI Express Gen 2 x1",
+        PciExpressGen2X2 = 0xAD => "PCI Express Gen 2 x2",
+        PciExpressGen2X4 = 0xAE => "PCI Express Gen 2 x4",
+        PciExpressGen2X8 = 0xAF => "PCI Express Gen 2 x8",
+        PciExpressGen2X16 = 0xB0 => "PCI Express Gen 2 x16",
+        PciExpressGen3 = 0xB1 => "PCI Express Gen 3",
+        PciExpressGen3X1 = 0xB2 => "PCI Express Gen 3 x1",
+        PciExpressGen3X2 = 0xB3 => "PCI Express Gen 3 x2",
+        PciExpressGen3X4 = 0xB4 => "PCI Express Gen 3 x4",
+        PciExpressGen3X8 = 0xB5 => "PCI Express Gen 3 x8",
+        PciExpressGen3X16 = 0xB6 => "PCI Express Gen 3 x16",
+        PciExpressGen4 = 0xB8 => "PCI Express Gen 4",
+        PciExpressGen4X1 = 0xB9 => "PCI Express Gen 4 x1",
+        PciExpressGen4X2 = 0xBA => "PCI Express Gen 4 x2",
+        PciExpressGen4X4 = 0xBB => "PCI Express Gen 4 x4",
+        PciExpressGen4X8 = 0xBC => "PCI Express Gen 4 x8",
+        PciExpressGen4X16 = 0xBD => "PCI Express Gen 4 x16",
+        PciExpressGen5 = 0xBE => "PCI Express Gen 5",
+        PciExpressGen5X1 = 0xBF => "PCI Express Gen 5 x1",
+        PciExpressGen5X2 = 0xC0 => "PCI Express Gen 5 x2",
+        PciExpressGen5X4 = 0xC1 => "PCI Express Gen 5 x4",
+        PciExpressGen5X8 = 0xC2 => "PCI Express Gen 5 x8",
+        PciExpressGen5X16 = 0xC3 => "PCI Express Gen 5 x16",
+        PciExpressGen6 = 0xC4 => "PCI Express Gen 6 and Beyond",
+        EdsffE1 = 0xC5 => "Enterprise and Datacenter 1U E1 Form Factor Slot (EDSFF E1.S, E1.L)",
+        EdsffE3 = 0xC6 => "Enterprise and Datacenter 3\" E3 Form Factor Slot (EDSFF E3.S, E3.L)",
+    }
+}
+
+spec_enum! {
+    /// Width of a slot.
+    pub enum SlotWidth: u8 {
+        Other = 0x01 => "Other",
+        Unknown = 0x02 => "Unknown",
+        Bit8 = 0x03 => "8 bit",
+        Bit16 = 0x04 => "16 bit",
+        Bit32 = 0x05 => "32 bit",
+        Bit64 = 0x06 => "64 bit",
+        Bit128 = 0x07 => "128 bit",
+        X1 = 0x08 => "1x or x1",
+        X2 = 0x09 => "2x or x2",
+        X4 = 0x0A => "4x or x4",
+        X8 = 0x0B => "8x or x8",
+        X12 = 0x0C => "12x or x12",
+        X16 = 0x0D => "16x or x16",
+        X32 = 0x0E => "32x or x32",
+    }
+}
+
+spec_enum! {
+    /// Usage of a slot.
+    pub enum SlotUsage: u8 {
+        Other = 0x01 => "Other",
+        Unknown = 0x02 => "Unknown",
+        Available = 0x03 => "Available",
+        InUse = 0x04 => "In use",
+        Unavailable = 0x05 => "Unavailable",
+    }
+}
+
+spec_enum! {
+    /// Physical length of a slot.
+    pub enum SlotLength: u8 {
+        Other = 0x01 => "Other",
+        Unknown = 0x02 => "Unknown",
+        Short = 0x03 => "Short Length",
+        Long = 0x04 => "Long Length",
+        DriveFormFactor2_5 = 0x05 => "2.5\" drive form factor",
+        DriveFormFactor3_5 = 0x06 => "3.5\" drive form factor",
+    }
+}
+
+spec_enum! {
+    /// Maximum height of a card in a slot.
+    pub enum SlotHeight: u8 {
+        NotApplicable = 0x00 => "Not applicable",
+        Other = 0x01 => "Other",
+        Unknown = 0x02 => "Unknown",
+        FullHeight = 0x03 => "Full height",
+        LowProfile = 0x04 => "Low-profile",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use table::StructureTable;
+
+    #[test]
+    fn fields_after_peer_groups() {
+        let mut data = [0; 0x22 + 2];
+        data[..0x04].copy_from_slice(&[9, 0x22, 0x00, 0x01]);
+        data[0x05] = 0xB6;
+        data[0x0D..0x11].copy_from_slice(&[0x00, 0x00, 0x3A, 0x1B]);
+        data[0x11] = 16;
+        // Two peer groups of 5 bytes each.
+        data[0x12] = 2;
+        data[0x13..0x18].copy_from_slice(&[0x00, 0x00, 0x3A, 0x10, 8]);
+        data[0x18..0x1D].copy_from_slice(&[0x01, 0x00, 0x40, 0x21, 8]);
+        data[0x1D] = 0x04;
+        data[0x1E] = 0x0D;
+        data[0x1F..0x21].copy_from_slice(&[0xD0, 0x07]);
+        data[0x21] = 0x04;
+
+        let raw = StructureTable::new(&data).iter().next().unwrap().unwrap();
+        let slot = SystemSlotView::new(raw).unwrap();
+        assert_eq!(slot.slot_type(), SlotType::PciExpressGen3X16);
+        assert_eq!(
+            slot.address(),
+            Some(PciAddress {
+                segment: 0,
+                bus: 0x3A,
+                device: 3,
+                function: 3,
+            })
+        );
+
+        let mut peers = slot.peer_groups();
+        assert_eq!(peers.len(), 2);
+        assert_eq!(
+            peers.next(),
+            Some(PeerDevice {
+                address: PciAddress {
+                    segment: 0,
+                    bus: 0x3A,
+                    device: 2,
+                    function: 0,
+                },
+                data_bus_width: 8,
+            })
+        );
+        assert_eq!(
+            peers.next(),
+            Some(PeerDevice {
+                address: PciAddress {
+                    segment: 1,
+                    bus: 0x40,
+                    device: 4,
+                    function: 1,
+                },
+                data_bus_width: 8,
+            })
+        );
+        assert_eq!(peers.next(), None);
+
+        assert_eq!(slot.slot_information(), Some(4));
+        assert_eq!(slot.physical_width(), Some(SlotWidth::X16));
+        assert_eq!(slot.pitch(), Some(2000));
+        assert_eq!(slot.height(), Some(SlotHeight::LowProfile));
+    }
+}
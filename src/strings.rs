@@ -0,0 +1,176 @@
+//! Accessing the string area of a structure.
+
+use core::str;
+
+use StringRef;
+
+/// The strings of a structure.
+///
+/// Strings are referenced by their index in the string area, counting from 1.
+/// A reference of 0 means the field has no string.
+#[derive(Debug, Copy, Clone)]
+pub struct StringSet<'a> {
+    area: &'a [u8],
+}
+
+impl<'a> StringSet<'a> {
+    /// Creates a string set from the string area of a structure.
+    pub fn new(area: &'a [u8]) -> Self {
+        StringSet { area }
+    }
+
+    /// Returns the string referenced by `index`, without the NULL-terminator.
+    ///
+    /// Returns `None` if `index` is 0 or there is no such string.
+    pub fn get(&self, index: StringRef) -> Option<&'a [u8]> {
+        if index == 0 {
+            return None;
+        }
+        self.iter().nth(index as usize - 1)
+    }
+
+    /// Returns the string referenced by `index` as UTF-8.
+    ///
+    /// The string is cut at the first byte which is not valid UTF-8.
+    pub fn get_str(&self, index: StringRef) -> Option<&'a str> {
+        self.get(index).map(to_str_lossy)
+    }
+
+    /// Returns an iterator over all the strings, in order.
+    pub fn iter(&self) -> Strings<'a> {
+        Strings { rest: self.area }
+    }
+
+    /// Returns an iterator over all the strings as UTF-8.
+    ///
+    /// Each string is cut at the first byte which is not valid UTF-8.
+    pub fn iter_lossy(&self) -> StringsLossy<'a> {
+        StringsLossy { inner: self.iter() }
+    }
+
+    /// Returns the set made of the first `count` strings.
+    pub fn take(&self, count: usize) -> StringSet<'a> {
+        let mut strings = self.iter();
+        for _ in strings.by_ref().take(count) {}
+        StringSet {
+            area: &self.area[..self.area.len() - strings.rest.len()],
+        }
+    }
+
+    /// Number of strings in the set.
+    pub fn len(&self) -> usize {
+        self.iter().count()
+    }
+
+    /// Returns true if the structure has no strings.
+    pub fn is_empty(&self) -> bool {
+        self.iter().next().is_none()
+    }
+}
+
+impl<'a> IntoIterator for StringSet<'a> {
+    type Item = &'a [u8];
+    type IntoIter = Strings<'a>;
+
+    fn into_iter(self) -> Strings<'a> {
+        self.iter()
+    }
+}
+
+/// Iterator over the strings of a structure.
+#[derive(Debug, Clone)]
+pub struct Strings<'a> {
+    rest: &'a [u8],
+}
+
+impl<'a> Iterator for Strings<'a> {
+    type Item = &'a [u8];
+
+    fn next(&mut self) -> Option<&'a [u8]> {
+        // An empty string marks the end of the area.
+        match self.rest.first() {
+            None | Some(&0) => return None,
+            Some(_) => (),
+        }
+
+        let len = self
+            .rest
+            .iter()
+            .position(|&b| b == 0)
+            .unwrap_or(self.rest.len());
+        let string = &self.rest[..len];
+        self.rest = &self.rest[(len + 1).min(self.rest.len())..];
+
+        Some(string)
+    }
+}
+
+/// Iterator over the strings of a structure as UTF-8.
+#[derive(Debug, Clone)]
+pub struct StringsLossy<'a> {
+    inner: Strings<'a>,
+}
+
+impl<'a> Iterator for StringsLossy<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        self.inner.next().map(to_str_lossy)
+    }
+}
+
+/// Interprets `bytes` as UTF-8, up to the first invalid byte.
+pub fn to_str_lossy(bytes: &[u8]) -> &str {
+    match str::from_utf8(bytes) {
+        Ok(s) => s,
+        Err(e) => str::from_utf8(&bytes[..e.valid_up_to()]).unwrap_or(""),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn get_is_one_based() {
+        let strings = StringSet::new(b"first\0second\0\0");
+        assert_eq!(strings.get(0), None);
+        assert_eq!(strings.get(1), Some(&b"first"[..]));
+        assert_eq!(strings.get(2), Some(&b"second"[..]));
+        assert_eq!(strings.get(3), None);
+        assert_eq!(strings.len(), 2);
+    }
+
+    #[test]
+    fn empty_area() {
+        let strings = StringSet::new(b"\0\0");
+        assert!(strings.is_empty());
+        assert_eq!(strings.get(1), None);
+    }
+
+    #[test]
+    fn lossy_cuts_at_invalid_utf8() {
+        assert_eq!(to_str_lossy(b"caf\xc3\xa9"), "caf\u{e9}");
+        assert_eq!(to_str_lossy(b"ab\xffcd"), "ab");
+        assert_eq!(to_str_lossy(b"\xc3"), "");
+    }
+
+    #[test]
+    fn take() {
+        let strings = StringSet::new(b"a\0b\0c\0\0");
+        assert_eq!(strings.take(0).len(), 0);
+        assert_eq!(strings.take(2).len(), 2);
+        assert_eq!(strings.take(2).get(2), Some(&b"b"[..]));
+        assert_eq!(strings.take(2).get(3), None);
+        assert_eq!(strings.take(5).len(), 3);
+    }
+
+    #[test]
+    fn iter_lossy() {
+        let strings = StringSet::new(b"ok\0bad\xff\0\0");
+        let mut iter = strings.iter_lossy();
+        assert_eq!(iter.next(), Some("ok"));
+        assert_eq!(iter.next(), Some("bad"));
+        assert_eq!(iter.next(), None);
+    }
+}
@@ -0,0 +1,184 @@
+//! System information (type 1).
+
+use core::fmt;
+
+use table::RawStructure;
+use {SmbiosVersion, Type};
+
+/// Safe view of a system information structure.
+#[derive(Debug, Copy, Clone)]
+pub struct SystemInformationView<'a> {
+    raw: RawStructure<'a>,
+}
+
+impl<'a> SystemInformationView<'a> {
+    /// Interprets a raw structure as system information.
+    ///
+    /// Returns `None` if the structure has a different type or is too short.
+    pub fn new(raw: RawStructure<'a>) -> Option<Self> {
+        if raw.ty() != Type::SystemInformation || raw.formatted().len() < 0x08 {
+            return None;
+        }
+        Some(SystemInformationView { raw })
+    }
+
+    /// The underlying structure.
+    pub fn raw(&self) -> RawStructure<'a> {
+        self.raw
+    }
+
+    /// System manufacturer.
+    pub fn manufacturer(&self) -> Option<&'a str> {
+        self.raw.string_lossy(0x04)
+    }
+
+    /// Product name.
+    pub fn product_name(&self) -> Option<&'a str> {
+        self.raw.string_lossy(0x05)
+    }
+
+    /// Product version.
+    pub fn version(&self) -> Option<&'a str> {
+        self.raw.string_lossy(0x06)
+    }
+
+    /// Serial number.
+    pub fn serial_number(&self) -> Option<&'a str> {
+        self.raw.string_lossy(0x07)
+    }
+
+    /// Universal unique ID of the system.
+    ///
+    /// The byte order of the first three fields depends on the `version` of the spec
+    /// the table conforms to. Only supported by SMBIOS 2.1+.
+    pub fn uuid(&self, version: SmbiosVersion) -> Option<Uuid> {
+        let bytes = self.raw.bytes(0x08, 16)?;
+        let mut uuid = [0; 16];
+        uuid.copy_from_slice(bytes);
+        Some(Uuid::from_smbios_bytes(uuid, version))
+    }
+
+    /// The event which caused the system to power up.
+    ///
+    /// Only supported by SMBIOS 2.1+.
+    pub fn wake_up_type(&self) -> Option<WakeUpType> {
+        self.raw.byte(0x18).map(WakeUpType::from)
+    }
+
+    /// Stock keeping unit of this system.
+    ///
+    /// Only supported by SMBIOS 2.4+.
+    pub fn sku_number(&self) -> Option<&'a str> {
+        self.raw.string_lossy(0x19)
+    }
+
+    /// Family to which this system belongs.
+    ///
+    /// Only supported by SMBIOS 2.4+.
+    pub fn family(&self) -> Option<&'a str> {
+        self.raw.string_lossy(0x1A)
+    }
+}
+
+/// An universally unique identifier.
+///
+/// The bytes are stored in the order defined by RFC 4122, with all fields big-endian.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Uuid(pub [u8; 16]);
+
+impl Uuid {
+    /// Converts a UUID from the byte order used by SMBIOS.
+    ///
+    /// Starting with SMBIOS 2.6, the first three fields are little-endian.
+    /// Older versions did not specify an order, and big-endian is assumed.
+    pub fn from_smbios_bytes(mut bytes: [u8; 16], version: SmbiosVersion) -> Self {
+        if version >= SmbiosVersion::new(2, 6, 0) {
+            bytes[0..4].reverse();
+            bytes[4..6].reverse();
+            bytes[6..8].reverse();
+        }
+        Uuid(bytes)
+    }
+
+    /// The bytes of this UUID, in RFC 4122 order.
+    pub fn as_bytes(&self) -> &[u8; 16] {
+        &self.0
+    }
+
+    /// Returns true if the ID is not present in the system.
+    ///
+    /// This is indicated by all bytes being 0.
+    pub fn is_not_present(&self) -> bool {
+        self.0.iter().all(|&b| b == 0x00)
+    }
+
+    /// Returns true if the ID is not currently set, but can be set.
+    ///
+    /// This is indicated by all bytes being 0xFF.
+    pub fn is_not_set(&self) -> bool {
+        self.0.iter().all(|&b| b == 0xFF)
+    }
+}
+
+impl fmt::Display for Uuid {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, b) in self.0.iter().enumerate() {
+            if i == 4 || i == 6 || i == 8 || i == 10 {
+                f.write_str("-")?;
+            }
+            write!(f, "{:02x}", b)?;
+        }
+        Ok(())
+    }
+}
+
+spec_enum! {
+    /// The event which caused the system to power up.
+    pub enum WakeUpType: u8 {
+        Reserved = 0x00 => "Reserved",
+        Other = 0x01 => "Other",
+        Unknown = 0x02 => "Unknown",
+        ApmTimer = 0x03 => "APM Timer",
+        ModemRing = 0x04 => "Modem Ring",
+        LanRemote = 0x05 => "LAN Remote",
+        PowerSwitch = 0x06 => "Power Switch",
+        PciPme = 0x07 => "PCI PME#",
+        AcPowerRestored = 0x08 => "AC Power Restored",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use self::std::string::ToString;
+    use super::*;
+
+    const BYTES: [u8; 16] = [
+        0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xAA, 0xBB, 0xCC, 0xDD, 0xEE,
+        0xFF,
+    ];
+
+    #[test]
+    fn uuid_before_2_6() {
+        let uuid = Uuid::from_smbios_bytes(BYTES, SmbiosVersion::new(2, 5, 0));
+        assert_eq!(uuid.to_string(), "00112233-4455-6677-8899-aabbccddeeff");
+    }
+
+    #[test]
+    fn uuid_since_2_6() {
+        let uuid = Uuid::from_smbios_bytes(BYTES, SmbiosVersion::new(2, 6, 0));
+        assert_eq!(uuid.to_string(), "33221100-5544-7766-8899-aabbccddeeff");
+    }
+
+    #[test]
+    fn uuid_sentinels() {
+        let version = SmbiosVersion::new(3, 0, 0);
+        let absent = Uuid::from_smbios_bytes([0x00; 16], version);
+        assert!(absent.is_not_present() && !absent.is_not_set());
+        let unset = Uuid::from_smbios_bytes([0xFF; 16], version);
+        assert!(unset.is_not_set() && !unset.is_not_present());
+        let uuid = Uuid::from_smbios_bytes(BYTES, version);
+        assert!(!uuid.is_not_present() && !uuid.is_not_set());
+    }
+}
@@ -0,0 +1,59 @@
+//! System configuration options (type 12).
+
+use strings::StringSet;
+use table::RawStructure;
+use Type;
+
+/// Safe view of a system configuration options structure.
+///
+/// Each string describes a jumper or switch setting on the board.
+#[derive(Debug, Copy, Clone)]
+pub struct SystemConfigurationOptionsView<'a> {
+    raw: RawStructure<'a>,
+}
+
+impl<'a> SystemConfigurationOptionsView<'a> {
+    /// Interprets a raw structure as system configuration options.
+    ///
+    /// Returns `None` if the structure has a different type or is too short.
+    pub fn new(raw: RawStructure<'a>) -> Option<Self> {
+        if raw.ty() != Type::SystemConfigurationOptions || raw.formatted().len() < 0x05 {
+            return None;
+        }
+        Some(SystemConfigurationOptionsView { raw })
+    }
+
+    /// The underlying structure.
+    pub fn raw(&self) -> RawStructure<'a> {
+        self.raw
+    }
+
+    /// Number of strings.
+    pub fn count(&self) -> u8 {
+        self.raw.byte(0x04).unwrap_or(0)
+    }
+
+    /// The strings of this structure.
+    ///
+    /// Strings past the count of the structure are ignored.
+    pub fn strings(&self) -> StringSet<'a> {
+        self.raw.strings().take(self.count() as usize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use table::TestStructure;
+
+    #[test]
+    fn strings_limited_to_count() {
+        let data = TestStructure::new(12, 0x05)
+            .field(0x04, &[1])
+            .strings(&[b"JP1: 1-2 Normal", b"extra"]);
+        let options = SystemConfigurationOptionsView::new(data.raw()).unwrap();
+        let mut strings = options.strings().iter();
+        assert_eq!(strings.next(), Some(&b"JP1: 1-2 Normal"[..]));
+        assert_eq!(strings.next(), None);
+    }
+}
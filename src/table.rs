@@ -0,0 +1,536 @@
+//! Walking the SMBIOS structure table.
+//!
+//! The table is a tightly packed array of structures of varying length.
+//! Each one is made out of a formatted area, starting with a `Header`,
+//! followed by a string area ending in a double NULL-terminator.
+
+use core::fmt;
+
+use strings::{to_str_lossy, StringSet};
+use {read_u16, read_u32, read_u64, Header, StringRef, Type};
+
+/// A view over the bytes of a SMBIOS structure table.
+#[derive(Debug, Copy, Clone)]
+pub struct StructureTable<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> StructureTable<'a> {
+    /// Creates a table from the bytes pointed to by an entry point.
+    ///
+    /// The slice should be bounded by the table size reported in the entry point.
+    pub fn new(data: &'a [u8]) -> Self {
+        StructureTable { data }
+    }
+
+    /// The underlying bytes of the table.
+    pub fn data(&self) -> &'a [u8] {
+        self.data
+    }
+
+    /// Returns an iterator over the structures in this table.
+    ///
+    /// The iterator stops after reporting the first malformed structure.
+    pub fn iter(&self) -> Structures<'a> {
+        Structures {
+            data: self.data,
+            offset: 0,
+            done: false,
+        }
+    }
+
+    /// Finds the structure with the given handle.
+    ///
+    /// Malformed structures are skipped, as with `iter_lenient`.
+    pub fn find_by_handle(&self, handle: u16) -> Option<RawStructure<'a>> {
+        self.iter_lenient().find(|s| s.handle() == handle)
+    }
+
+    /// Returns an iterator which recovers from malformed structures.
+    ///
+    /// Structures with an invalid length are skipped, and a structure whose strings
+    /// are cut off by the end of the table is returned with a truncated string area.
+    pub fn iter_lenient(&self) -> LenientStructures<'a> {
+        LenientStructures {
+            data: self.data,
+            offset: 0,
+            done: false,
+        }
+    }
+}
+
+impl<'a> IntoIterator for &StructureTable<'a> {
+    type Item = Result<RawStructure<'a>, TableError>;
+    type IntoIter = Structures<'a>;
+
+    fn into_iter(self) -> Structures<'a> {
+        self.iter()
+    }
+}
+
+/// Iterator over the structures in a table.
+///
+/// Iteration stops after the End-of-Table structure, at the end of the table,
+/// or after the first malformed structure.
+#[derive(Debug, Clone)]
+pub struct Structures<'a> {
+    data: &'a [u8],
+    offset: usize,
+    done: bool,
+}
+
+impl<'a> Iterator for Structures<'a> {
+    type Item = Result<RawStructure<'a>, TableError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done || self.offset == self.data.len() {
+            return None;
+        }
+
+        match parse_structure(self.data, self.offset) {
+            Ok(structure) => {
+                self.offset = structure.end();
+                self.done = structure.ty() == Type::EndOfTable;
+                Some(Ok(structure))
+            }
+            Err(err) => {
+                self.done = true;
+                Some(Err(err))
+            }
+        }
+    }
+}
+
+/// Iterator over the structures in a table, skipping malformed ones.
+///
+/// See `StructureTable::iter_lenient` for more information.
+#[derive(Debug, Clone)]
+pub struct LenientStructures<'a> {
+    data: &'a [u8],
+    offset: usize,
+    done: bool,
+}
+
+impl<'a> Iterator for LenientStructures<'a> {
+    type Item = RawStructure<'a>;
+
+    fn next(&mut self) -> Option<RawStructure<'a>> {
+        while !self.done && self.offset < self.data.len() {
+            let err = match parse_structure(self.data, self.offset) {
+                Ok(structure) => {
+                    self.offset = structure.end();
+                    self.done = structure.ty() == Type::EndOfTable;
+                    return Some(structure);
+                }
+                Err(err) => err,
+            };
+
+            let rest = &self.data[self.offset..];
+            match err.kind {
+                // Assume the structure only has a header, and skip to the end of its strings.
+                TableErrorKind::BadLength(_) => match find_terminator(&rest[4..]) {
+                    Some(strings_len) => self.offset += 4 + strings_len,
+                    None => self.done = true,
+                },
+                TableErrorKind::UnterminatedStrings => {
+                    self.done = true;
+                    let len = rest[1] as usize;
+                    return Some(RawStructure {
+                        offset: self.offset,
+                        formatted: &rest[..len],
+                        strings: &rest[len..],
+                    });
+                }
+                TableErrorKind::TruncatedHeader | TableErrorKind::TruncatedFormatted => {
+                    self.done = true;
+                }
+            }
+        }
+
+        None
+    }
+}
+
+/// Parses the structure starting at `offset` in the table.
+fn parse_structure<'a>(data: &'a [u8], offset: usize) -> Result<RawStructure<'a>, TableError> {
+    let rest = &data[offset..];
+    if rest.len() < 4 {
+        return Err(TableError {
+            offset,
+            handle: None,
+            kind: TableErrorKind::TruncatedHeader,
+        });
+    }
+
+    let error = |kind| TableError {
+        offset,
+        handle: Some(read_u16(rest, 2)),
+        kind,
+    };
+
+    let len = rest[1] as usize;
+    if len < 4 {
+        return Err(error(TableErrorKind::BadLength(rest[1])));
+    }
+    if len > rest.len() {
+        return Err(error(TableErrorKind::TruncatedFormatted));
+    }
+
+    let strings_len =
+        find_terminator(&rest[len..]).ok_or_else(|| error(TableErrorKind::UnterminatedStrings))?;
+
+    Ok(RawStructure {
+        offset,
+        formatted: &rest[..len],
+        strings: &rest[len..len + strings_len],
+    })
+}
+
+/// Returns the length of a string area, including the double NULL-terminator.
+fn find_terminator(strings: &[u8]) -> Option<usize> {
+    strings
+        .windows(2)
+        .position(|w| w == [0, 0])
+        .map(|pos| pos + 2)
+}
+
+/// A malformed structure found while walking a table.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct TableError {
+    /// Offset of the structure from the start of the table.
+    pub offset: usize,
+    /// Handle of the structure, if its header could be read.
+    pub handle: Option<u16>,
+    /// What is wrong with the structure.
+    pub kind: TableErrorKind,
+}
+
+/// Reasons for which a structure can be malformed.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum TableErrorKind {
+    /// The table ends before the header of the structure.
+    TruncatedHeader,
+    /// The length of the formatted area is smaller than the header.
+    BadLength(u8),
+    /// The table ends before the formatted area of the structure.
+    TruncatedFormatted,
+    /// The table ends before the double NULL-terminator of the string area.
+    UnterminatedStrings,
+}
+
+impl fmt::Display for TableError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "malformed structure at offset {:#x}", self.offset)?;
+        if let Some(handle) = self.handle {
+            write!(f, " (handle {:#06x})", handle)?;
+        }
+        f.write_str(": ")?;
+
+        match self.kind {
+            TableErrorKind::TruncatedHeader => f.write_str("header is truncated"),
+            TableErrorKind::BadLength(len) => write!(f, "invalid length {}", len),
+            TableErrorKind::TruncatedFormatted => f.write_str("formatted area is truncated"),
+            TableErrorKind::UnterminatedStrings => f.write_str("string area is not terminated"),
+        }
+    }
+}
+
+/// A structure from the table, not yet interpreted.
+#[derive(Debug, Copy, Clone)]
+pub struct RawStructure<'a> {
+    offset: usize,
+    formatted: &'a [u8],
+    strings: &'a [u8],
+}
+
+impl<'a> RawStructure<'a> {
+    /// The header of this structure.
+    pub fn header(&self) -> Header {
+        Header {
+            ty: self.formatted[0],
+            len: self.formatted[1],
+            handle: self.handle(),
+        }
+    }
+
+    /// Type of this structure.
+    pub fn ty(&self) -> Type {
+        Type::from(self.formatted[0])
+    }
+
+    /// The unique handle of this structure.
+    pub fn handle(&self) -> u16 {
+        read_u16(self.formatted, 2)
+    }
+
+    /// Offset of this structure from the start of the table.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Offset of the first byte after this structure.
+    fn end(&self) -> usize {
+        self.offset + self.formatted.len() + self.strings.len()
+    }
+
+    /// The formatted area of this structure, including the header.
+    pub fn formatted(&self) -> &'a [u8] {
+        self.formatted
+    }
+
+    /// The string area of this structure, including the double NULL-terminator.
+    ///
+    /// Structures returned by a lenient iterator might be missing the terminator.
+    pub fn string_area(&self) -> &'a [u8] {
+        self.strings
+    }
+
+    /// The strings of this structure.
+    pub fn strings(&self) -> StringSet<'a> {
+        StringSet::new(self.strings)
+    }
+
+    /// Resolves the string referenced by the `StringRef` at `offset` in the formatted area.
+    ///
+    /// Returns `None` if the field is missing, or it does not reference a string.
+    pub fn string(&self, offset: usize) -> Option<&'a [u8]> {
+        self.byte(offset)
+            .and_then(|index: StringRef| self.strings().get(index))
+    }
+
+    /// Resolves the string referenced at `offset` as UTF-8.
+    ///
+    /// The string is cut at the first byte which is not valid UTF-8.
+    pub fn string_lossy(&self, offset: usize) -> Option<&'a str> {
+        self.string(offset).map(to_str_lossy)
+    }
+
+    /// Reads the byte at `offset` in the formatted area.
+    ///
+    /// Returns `None` if the structure is too short, which usually means
+    /// the field was introduced by a newer version of the spec.
+    pub fn byte(&self, offset: usize) -> Option<u8> {
+        self.formatted.get(offset).cloned()
+    }
+
+    /// Reads the little-endian word at `offset` in the formatted area.
+    pub fn word(&self, offset: usize) -> Option<u16> {
+        self.bytes(offset, 2).map(|b| read_u16(b, 0))
+    }
+
+    /// Reads the little-endian double word at `offset` in the formatted area.
+    pub fn dword(&self, offset: usize) -> Option<u32> {
+        self.bytes(offset, 4).map(|b| read_u32(b, 0))
+    }
+
+    /// Reads the little-endian quad word at `offset` in the formatted area.
+    pub fn qword(&self, offset: usize) -> Option<u64> {
+        self.bytes(offset, 8).map(|b| read_u64(b, 0))
+    }
+
+    /// Returns `len` bytes starting at `offset` in the formatted area.
+    pub fn bytes(&self, offset: usize, len: usize) -> Option<&'a [u8]> {
+        let end = offset.checked_add(len)?;
+        self.formatted.get(offset..end)
+    }
+
+    /// Returns an iterator over a list of `count` handles starting at `offset`.
+    ///
+    /// The list is cut short if it does not fit in the formatted area.
+    pub fn handles(&self, offset: usize, count: usize) -> Handles<'a> {
+        let start = offset.min(self.formatted.len());
+        let end = offset.saturating_add(2 * count).min(self.formatted.len());
+        Handles {
+            bytes: &self.formatted[start..end],
+        }
+    }
+}
+
+/// Iterator over a list of structure handles.
+#[derive(Debug, Clone)]
+pub struct Handles<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Iterator for Handles<'a> {
+    type Item = u16;
+
+    fn next(&mut self) -> Option<u16> {
+        if self.bytes.len() < 2 {
+            return None;
+        }
+        let handle = read_u16(self.bytes, 0);
+        self.bytes = &self.bytes[2..];
+        Some(handle)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.bytes.len() / 2;
+        (len, Some(len))
+    }
+}
+
+impl<'a> ExactSizeIterator for Handles<'a> {}
+
+/// A structure built in memory, for testing the views.
+#[cfg(test)]
+pub struct TestStructure {
+    data: [u8; 0x200],
+    len: usize,
+    strings_len: usize,
+}
+
+#[cfg(test)]
+impl TestStructure {
+    /// Creates a structure of type `ty`, with a zeroed formatted area of `len` bytes
+    /// and no strings.
+    pub fn new(ty: u8, len: u8) -> Self {
+        let mut data = [0; 0x200];
+        data[0] = ty;
+        data[1] = len;
+        TestStructure {
+            data,
+            len: len as usize,
+            strings_len: 2,
+        }
+    }
+
+    /// Sets the handle of the structure.
+    pub fn handle(mut self, handle: u16) -> Self {
+        self.data[2] = handle as u8;
+        self.data[3] = (handle >> 8) as u8;
+        self
+    }
+
+    /// Writes `bytes` at `offset` in the formatted area.
+    pub fn field(mut self, offset: usize, bytes: &[u8]) -> Self {
+        assert!(offset >= 4 && offset + bytes.len() <= self.len);
+        self.data[offset..offset + bytes.len()].copy_from_slice(bytes);
+        self
+    }
+
+    /// Sets the strings of the structure.
+    pub fn strings(mut self, strings: &[&[u8]]) -> Self {
+        let mut end = self.len;
+        for string in strings {
+            self.data[end..end + string.len()].copy_from_slice(string);
+            self.data[end + string.len()] = 0;
+            end += string.len() + 1;
+        }
+        self.data[end] = 0;
+        self.strings_len = (end + 1 - self.len).max(2);
+        self
+    }
+
+    /// The bytes of the whole structure, as found in a table.
+    pub fn bytes(&self) -> &[u8] {
+        &self.data[..self.len + self.strings_len]
+    }
+
+    /// Parses the structure.
+    pub fn raw<'a>(&'a self) -> RawStructure<'a> {
+        StructureTable::new(self.bytes())
+            .iter()
+            .next()
+            .unwrap()
+            .unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bad_length() {
+        let data = [
+            1, 4, 1, 0, 0, 0, // Valid structure.
+            2, 2, 2, 0, 0, 0, // Length smaller than the header.
+            127, 4, 3, 0, 0, 0,
+        ];
+        let table = StructureTable::new(&data);
+
+        let mut strict = table.iter();
+        assert_eq!(strict.next().unwrap().unwrap().handle(), 1);
+        assert_eq!(
+            strict.next().unwrap().unwrap_err(),
+            TableError {
+                offset: 6,
+                handle: Some(2),
+                kind: TableErrorKind::BadLength(2),
+            }
+        );
+        assert!(strict.next().is_none());
+
+        let mut lenient = table.iter_lenient();
+        assert_eq!(lenient.next().unwrap().handle(), 1);
+        assert_eq!(lenient.next().unwrap().handle(), 3);
+        assert!(lenient.next().is_none());
+    }
+
+    #[test]
+    fn unterminated_strings() {
+        let data = [1, 4, 1, 0, 0, 0, 1, 5, 2, 0, 1, b'a', b'b', 0];
+        let table = StructureTable::new(&data);
+
+        let mut strict = table.iter();
+        assert!(strict.next().unwrap().is_ok());
+        assert_eq!(
+            strict.next().unwrap().unwrap_err().kind,
+            TableErrorKind::UnterminatedStrings
+        );
+
+        let mut lenient = table.iter_lenient();
+        assert_eq!(lenient.next().unwrap().handle(), 1);
+        let truncated = lenient.next().unwrap();
+        assert_eq!(truncated.handle(), 2);
+        assert_eq!(truncated.string_area(), b"ab\0");
+        assert_eq!(truncated.string(0x04), Some(&b"ab"[..]));
+        assert!(lenient.next().is_none());
+    }
+
+    #[test]
+    fn truncated_header() {
+        let data = [1, 4, 1, 0, 0, 0, 1, 4];
+        let mut strict = StructureTable::new(&data).iter();
+        assert!(strict.next().unwrap().is_ok());
+        assert_eq!(
+            strict.next().unwrap().unwrap_err(),
+            TableError {
+                offset: 6,
+                handle: None,
+                kind: TableErrorKind::TruncatedHeader,
+            }
+        );
+        assert!(strict.next().is_none());
+    }
+
+    #[test]
+    fn truncated_formatted() {
+        let data = [1, 4, 1, 0, 0, 0, 1, 8, 5, 0, 0];
+        let mut strict = StructureTable::new(&data).iter();
+        assert!(strict.next().unwrap().is_ok());
+        assert_eq!(
+            strict.next().unwrap().unwrap_err(),
+            TableError {
+                offset: 6,
+                handle: Some(5),
+                kind: TableErrorKind::TruncatedFormatted,
+            }
+        );
+        assert!(strict.next().is_none());
+    }
+
+    #[test]
+    fn stops_at_end_of_table() {
+        let data = [
+            1, 4, 1, 0, 0, 0, //
+            127, 4, 2, 0, 0, 0, //
+            1, 4, 3, 0, 0, 0,
+        ];
+        let table = StructureTable::new(&data);
+        assert_eq!(table.iter().count(), 2);
+        assert_eq!(table.iter_lenient().count(), 2);
+        assert!(table.find_by_handle(3).is_none());
+    }
+}
@@ -0,0 +1,188 @@
+//! Resolving the caches of processors.
+
+use cache::CacheInformationView;
+use processor::ProcessorInformationView;
+use table::{LenientStructures, StructureTable};
+
+/// The caches of a processor, resolved from the handles in its structure.
+#[derive(Debug, Copy, Clone)]
+pub struct ProcessorCaches<'a> {
+    /// Level 1 cache.
+    pub l1: CacheLink<'a>,
+    /// Level 2 cache.
+    pub l2: CacheLink<'a>,
+    /// Level 3 cache.
+    pub l3: CacheLink<'a>,
+}
+
+impl<'a> ProcessorCaches<'a> {
+    /// Resolves the caches of `processor` by looking up their handles in `table`.
+    pub fn resolve(table: &StructureTable<'a>, processor: &ProcessorInformationView<'a>) -> Self {
+        ProcessorCaches {
+            l1: CacheLink::resolve(table, processor.l1_cache_handle()),
+            l2: CacheLink::resolve(table, processor.l2_cache_handle()),
+            l3: CacheLink::resolve(table, processor.l3_cache_handle()),
+        }
+    }
+
+    /// The caches, ordered by level.
+    pub fn levels(&self) -> [CacheLink<'a>; 3] {
+        [self.l1, self.l2, self.l3]
+    }
+
+    /// Returns true if any of the cache handles could not be resolved.
+    pub fn has_dangling(&self) -> bool {
+        self.levels().iter().any(|link| link.dangling().is_some())
+    }
+}
+
+/// Link from a processor to one of its caches.
+#[derive(Debug, Copy, Clone)]
+pub enum CacheLink<'a> {
+    /// The processor does not have a cache at this level,
+    /// or the structure does not reference it.
+    None,
+    /// The referenced cache structure.
+    Resolved(CacheInformationView<'a>),
+    /// The handle does not reference a valid cache structure.
+    Dangling(u16),
+}
+
+impl<'a> CacheLink<'a> {
+    fn resolve(table: &StructureTable<'a>, handle: Option<u16>) -> Self {
+        let handle = match handle {
+            Some(handle) => handle,
+            None => return CacheLink::None,
+        };
+
+        match table
+            .find_by_handle(handle)
+            .and_then(CacheInformationView::new)
+        {
+            Some(cache) => CacheLink::Resolved(cache),
+            None => CacheLink::Dangling(handle),
+        }
+    }
+
+    /// The cache, if it was resolved.
+    pub fn cache(&self) -> Option<CacheInformationView<'a>> {
+        match *self {
+            CacheLink::Resolved(cache) => Some(cache),
+            _ => None,
+        }
+    }
+
+    /// The handle which could not be resolved, if any.
+    pub fn dangling(&self) -> Option<u16> {
+        match *self {
+            CacheLink::Dangling(handle) => Some(handle),
+            _ => None,
+        }
+    }
+}
+
+/// Iterator over the processors in a table, together with their caches.
+#[derive(Debug, Clone)]
+pub struct ProcessorTopology<'a> {
+    table: StructureTable<'a>,
+    structures: LenientStructures<'a>,
+}
+
+impl<'a> ProcessorTopology<'a> {
+    /// Creates an iterator over the processors in `table`.
+    pub fn new(table: &StructureTable<'a>) -> Self {
+        ProcessorTopology {
+            table: *table,
+            structures: table.iter_lenient(),
+        }
+    }
+}
+
+impl<'a> Iterator for ProcessorTopology<'a> {
+    type Item = (ProcessorInformationView<'a>, ProcessorCaches<'a>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let processor = self
+            .structures
+            .by_ref()
+            .filter_map(ProcessorInformationView::new)
+            .next()?;
+        let caches = ProcessorCaches::resolve(&self.table, &processor);
+        Some((processor, caches))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use self::std::vec::Vec;
+    use super::*;
+    use table::TestStructure;
+
+    /// Builds a processor referencing the given L1, L2 and L3 cache handles.
+    fn processor(handle: u16, caches: [u16; 3]) -> TestStructure {
+        let mut handles = [0; 6];
+        for (i, cache) in caches.iter().enumerate() {
+            handles[2 * i] = *cache as u8;
+            handles[2 * i + 1] = (*cache >> 8) as u8;
+        }
+        TestStructure::new(4, 0x20)
+            .handle(handle)
+            .field(0x1A, &handles)
+    }
+
+    fn table(structures: &[TestStructure]) -> Vec<u8> {
+        let mut data = Vec::new();
+        for structure in structures {
+            data.extend_from_slice(structure.bytes());
+        }
+        data.extend_from_slice(TestStructure::new(127, 4).handle(0xFEFF).bytes());
+        data
+    }
+
+    #[test]
+    fn resolve_caches() {
+        let data = table(&[
+            processor(0x10, [0x20, 0xFFFF, 0x30]),
+            TestStructure::new(7, 0x13).handle(0x20),
+            TestStructure::new(2, 0x08).handle(0x40),
+            processor(0x11, [0x40, 0x20, 0xFFFF]),
+        ]);
+        let table = StructureTable::new(&data);
+        let mut topology = ProcessorTopology::new(&table);
+
+        let (processor, caches) = topology.next().unwrap();
+        assert_eq!(processor.raw().handle(), 0x10);
+        assert_eq!(caches.l1.cache().unwrap().raw().handle(), 0x20);
+        match caches.l2 {
+            CacheLink::None => (),
+            link => panic!("unexpected L2 cache {:?}", link),
+        }
+        assert_eq!(caches.l3.dangling(), Some(0x30));
+        assert!(caches.has_dangling());
+
+        // The L1 handle points at a baseboard, not a cache.
+        let (processor, caches) = topology.next().unwrap();
+        assert_eq!(processor.raw().handle(), 0x11);
+        assert_eq!(caches.l1.dangling(), Some(0x40));
+        assert_eq!(caches.l2.cache().unwrap().raw().handle(), 0x20);
+        assert!(caches.l3.cache().is_none() && caches.l3.dangling().is_none());
+        assert!(caches.has_dangling());
+
+        assert!(topology.next().is_none());
+    }
+
+    #[test]
+    fn no_dangling_caches() {
+        let data = table(&[
+            TestStructure::new(7, 0x13).handle(0x20),
+            processor(0x10, [0x20, 0xFFFF, 0xFFFF]),
+        ]);
+        let table = StructureTable::new(&data);
+        let processor = ProcessorTopology::new(&table).next().unwrap().0;
+        let caches = ProcessorCaches::resolve(&table, &processor);
+        assert!(!caches.has_dangling());
+        assert!(caches.levels()[0].cache().is_some());
+    }
+}